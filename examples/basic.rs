@@ -1,9 +1,8 @@
-use telera_layout::{Color, ElementConfiguration, LayoutEngine, MeasureText, RenderCommand, TextConfig, Vec2};
+use telera_layout::{Color, ElementConfiguration, LayoutEngine, MeasureText, RenderCommand, Sizing, TextConfig, Vec2};
 
 #[derive(Debug, Default)]
 struct LayoutRenderer{
     pub mt: Vec2,
-    s: String
 }
 
 impl MeasureText for LayoutRenderer {
@@ -14,74 +13,65 @@ impl MeasureText for LayoutRenderer {
 
 impl LayoutRenderer {
     pub fn new() -> Self {
-        Self { mt: Vec2 { x: 30.0, y: 30.0 }, s: "what's up".to_string() }
+        Self { mt: Vec2 { x: 30.0, y: 30.0 } }
     }
 }
 
 fn main() {
-    let mut layout_renderer = LayoutRenderer::new();
-
-    let mut layout = LayoutEngine::<(),(),()>::new((500.0,500.0));
+    let mut layout = LayoutEngine::<(),(),()>::new((500.0,500.0), LayoutRenderer::new());
     
     layout.begin_layout();
 
-    layout.open_element();
-
     let config = ElementConfiguration::new()
         .id("hi")
-        .x_grow()
-        .y_grow()
+        .width(Sizing::grow())
+        .height(Sizing::grow())
         .padding_all(5)
         .color(Color{r:5.0,g:7.0,b:9.0,a:255.0})
         .end();
-    layout.configure_element(&config);
-
-    let text_config = crate::TextConfig::new()
-        .font_id(0)
-        .color(crate::Color::default())
-        .font_size(12)
-        .line_height(14)
-        .end();
-    layout.add_text_element("hi1", &text_config, true, &mut layout_renderer);
-
-    let text_config = crate::TextConfig::new()
-        .font_id(0)
-        .color(crate::Color::default())
-        .font_size(45)
-        .line_height(50)
-        .end();
-    layout.add_text_element("hi2", &text_config, true, &mut layout_renderer);
-
-    let text_config = crate::TextConfig::new()
-        .font_id(0)
-        .color(crate::Color::default())
-        .font_size(12)
-        .line_height(14)
-        .end();
-    layout.add_text_element("hi3", &text_config, true, &mut layout_renderer);
-
-    layout.open_element();
-    let config = crate::ElementConfiguration::new()
-        .id("test")
-        .x_fixed(50.0)
-        .y_fixed(50.0)
-        .color(crate::Color::default())
-        .end();
-    layout.configure_element(&config);
-    layout.close_element();
 
-    layout.open_element();
-    let config = crate::ElementConfiguration::new()
-        .x_grow()
-        .y_grow()
-        .color(crate::Color::default())
-        .end();
-    layout.configure_element(&config);
-    layout.close_element();
-
-    layout.close_element();
-
-    let render_commands = layout.end_layout(&mut layout_renderer);
+    layout.element(&config, |layout| {
+        let text_config = crate::TextConfig::new()
+            .font_id(0)
+            .color(crate::Color::default())
+            .font_size(12)
+            .line_height(14)
+            .end();
+        layout.text("hi1", &text_config);
+
+        let text_config = crate::TextConfig::new()
+            .font_id(0)
+            .color(crate::Color::default())
+            .font_size(45)
+            .line_height(50)
+            .end();
+        layout.text("hi2", &text_config);
+
+        let text_config = crate::TextConfig::new()
+            .font_id(0)
+            .color(crate::Color::default())
+            .font_size(12)
+            .line_height(14)
+            .end();
+        layout.text(format!("hi{}", 3), &text_config);
+
+        let config = crate::ElementConfiguration::new()
+            .id("test")
+            .width(Sizing::Fixed(50.0))
+            .height(Sizing::Fixed(50.0))
+            .color(crate::Color::default())
+            .end();
+        layout.element(&config, |_| {});
+
+        let config = crate::ElementConfiguration::new()
+            .width(Sizing::grow())
+            .height(Sizing::grow())
+            .color(crate::Color::default())
+            .end();
+        layout.element(&config, |_| {});
+    });
+
+    let render_commands = layout.end_layout();
 
     for command in render_commands {
         match command {
@@ -93,6 +83,4 @@ fn main() {
         }
     }
 
-    println!("{:?}", layout_renderer.s);
-
 }
\ No newline at end of file
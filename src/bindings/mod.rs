@@ -61,6 +61,13 @@ pub struct BoundingBox{
     pub height: f32,
 }
 
+impl BoundingBox {
+    /// Mirrors the box horizontally within a layout of the given width.
+    pub(crate) fn mirror(&mut self, layout_width: f32) {
+        self.x = layout_width - self.x - self.width;
+    }
+}
+
 impl Into<BoundingBox> for Clay_BoundingBox {
     fn into(self) -> BoundingBox {
         BoundingBox { x: self.x, y: self.y, width: self.width, height: self.height }
@@ -68,7 +75,7 @@ impl Into<BoundingBox> for Clay_BoundingBox {
 }
 
 #[repr(C)]
-#[derive(Debug, Clone, Copy, Default)]
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
 pub struct Vec2{
     pub x: f32,
     pub y: f32
@@ -99,6 +106,13 @@ pub struct CornerRadii {
     pub bottom_right: f32,
 }
 
+impl CornerRadii {
+    pub(crate) fn mirror(&mut self) {
+        std::mem::swap(&mut self.top_left, &mut self.top_right);
+        std::mem::swap(&mut self.bottom_left, &mut self.bottom_right);
+    }
+}
+
 impl From<Clay_CornerRadius> for CornerRadii {
     fn from(value: Clay_CornerRadius) -> Self {
         Self {
@@ -125,6 +139,12 @@ pub struct BorderWidth {
     pub between_children: u16,
 }
 
+impl BorderWidth {
+    pub(crate) fn mirror(&mut self) {
+        std::mem::swap(&mut self.left, &mut self.right);
+    }
+}
+
 impl Into<BorderWidth> for Clay_BorderWidth {
     fn into(self) -> BorderWidth {
         BorderWidth { left: self.left, right: self.right, top: self.top, bottom: self.bottom, between_children: self.betweenChildren }
@@ -218,9 +238,11 @@ pub struct Image<'render_pass, ImageElementData, CustomLayoutSettings> {
     pub custom_layout_settings: Option<&'render_pass CustomLayoutSettings>,
     /// Background color
     pub background_color: Color,
-    /// The dimensions of the image.
+    /// The corner radii for rounded edges.
+    pub corner_radii: CornerRadii,
+    /// The natural size of the image if one was declared, otherwise the size of the element.
     pub dimensions: Vec2,
-    /// A pointer to the image data.
+    /// The image data stored for the element.
     pub data: &'render_pass ImageElementData,
 }
 
@@ -242,30 +264,30 @@ pub struct Custom<'render_pass, CustomElementData, CustomLayoutSettings> {
     pub background_color: Color,
     /// The corner radii for rounded edges.
     pub corner_radii: CornerRadii,
-    /// A pointer to additional custom data.
+    /// The custom data stored for the element.
     pub data: &'render_pass CustomElementData,
 }
 
-impl<'render_pass, CustomLayoutSettings> From<&Clay_RenderCommand> for Rectangle<'render_pass, CustomLayoutSettings> {
-    fn from(value: &Clay_RenderCommand) -> Self {
+impl<'render_pass, CustomLayoutSettings> Rectangle<'render_pass, CustomLayoutSettings> {
+    pub(crate) fn new(value: &Clay_RenderCommand, custom_layout_settings: Option<&'render_pass CustomLayoutSettings>) -> Self {
         Rectangle { 
             bounding_box: value.boundingBox.into(), 
             id: value.id, 
             z_index: value.zIndex,
-            custom_layout_settings: unsafe {value.userData.cast::<CustomLayoutSettings>().as_ref()},
+            custom_layout_settings,
             color: unsafe { value.renderData.rectangle.backgroundColor.into() }, 
             corner_radii: unsafe { value.renderData.rectangle.cornerRadius.into() }
         }
     }
 }
 
-impl<'render_pass, CustomLayoutSettings> From<&Clay_RenderCommand> for Border<'render_pass, CustomLayoutSettings> {
-    fn from(value: &Clay_RenderCommand) -> Self {
+impl<'render_pass, CustomLayoutSettings> Border<'render_pass, CustomLayoutSettings> {
+    pub(crate) fn new(value: &Clay_RenderCommand, custom_layout_settings: Option<&'render_pass CustomLayoutSettings>) -> Self {
         Border { 
             bounding_box: value.boundingBox.into(), 
             id: value.id, 
             z_index: value.zIndex, 
-            custom_layout_settings: unsafe {value.userData.cast::<CustomLayoutSettings>().as_ref()},
+            custom_layout_settings,
             color: unsafe { value.renderData.border.color.into() }, 
             corner_radii: unsafe { value.renderData.border.cornerRadius.into() }, 
             width: unsafe { value.renderData.border.width.into() } 
@@ -273,13 +295,13 @@ impl<'render_pass, CustomLayoutSettings> From<&Clay_RenderCommand> for Border<'r
     }
 }
 
-impl<'render_pass, CustomLayoutSettings> From<&Clay_RenderCommand> for Text<'render_pass, CustomLayoutSettings> {
-    fn from(value: &Clay_RenderCommand) -> Self {
+impl<'render_pass, CustomLayoutSettings> Text<'render_pass, CustomLayoutSettings> {
+    pub(crate) fn new(value: &Clay_RenderCommand, custom_layout_settings: Option<&'render_pass CustomLayoutSettings>) -> Self {
         Text { 
             bounding_box: value.boundingBox.into(), 
             id: value.id, 
             z_index: value.zIndex,
-            custom_layout_settings: unsafe {value.userData.cast::<CustomLayoutSettings>().as_ref()},
+            custom_layout_settings,
             text: unsafe {
                 core::str::from_utf8_unchecked(core::slice::from_raw_parts(
                     value.renderData.text.stringContents.chars as *const u8,
@@ -301,30 +323,31 @@ impl<'render_pass> From<&Clay_RenderCommand> for BoundingBox {
     }
 }
 
-impl<'render_pass, ImageElementData, CustomLayoutSettings> From<&Clay_RenderCommand> for Image<'render_pass, ImageElementData, CustomLayoutSettings>{
-    fn from(value: &Clay_RenderCommand) -> Self {
+impl<'render_pass, ImageElementData, CustomLayoutSettings> Image<'render_pass, ImageElementData, CustomLayoutSettings>{
+    pub(crate) fn new(value: &Clay_RenderCommand, data: &'render_pass ImageElementData, custom_layout_settings: Option<&'render_pass CustomLayoutSettings>) -> Self {
         Image { 
             bounding_box: value.boundingBox.into(), 
             id: value.id, 
             z_index: value.zIndex, 
-            custom_layout_settings: unsafe {value.userData.cast::<CustomLayoutSettings>().as_ref()},
+            custom_layout_settings,
             background_color: unsafe { value.renderData.image.backgroundColor.into() }, 
-            dimensions: Vec2 { x: 0.0, y: 0.0 }, 
-            data: unsafe { &*value.renderData.image.imageData.cast() }
+            corner_radii: unsafe { value.renderData.image.cornerRadius.into() }, 
+            dimensions: Vec2 { x: value.boundingBox.width, y: value.boundingBox.height }, 
+            data,
         }
     }
 }
 
-impl<CustomElementData, CustomLayoutSettings> From<&Clay_RenderCommand> for Custom<'_, CustomElementData, CustomLayoutSettings> {
-    fn from(value: &Clay_RenderCommand) -> Self {
+impl<'render_pass, CustomElementData, CustomLayoutSettings> Custom<'render_pass, CustomElementData, CustomLayoutSettings> {
+    pub(crate) fn new(value: &Clay_RenderCommand, data: &'render_pass CustomElementData, custom_layout_settings: Option<&'render_pass CustomLayoutSettings>) -> Self {
         Custom {
             bounding_box: value.boundingBox.into(), 
             id: value.id, 
             z_index: value.zIndex,
-            custom_layout_settings: unsafe {value.userData.cast::<CustomLayoutSettings>().as_ref()},
+            custom_layout_settings,
             background_color: unsafe { value.renderData.custom.backgroundColor.into() }, 
             corner_radii: unsafe { value.renderData.custom.cornerRadius.into() }, 
-            data: unsafe { &*value.renderData.custom.customData.cast() },
+            data,
         }
     }
 }
@@ -340,3 +363,31 @@ pub enum RenderCommand<'render_pass, ImageElementData, CustomElementData, Custom
     Image(Image<'render_pass, ImageElementData, CustomLayoutSettings>),
     Custom(Custom<'render_pass, CustomElementData, CustomLayoutSettings>),
 }
+
+impl<ImageElementData, CustomElementData, CustomLayoutSettings> RenderCommand<'_, ImageElementData, CustomElementData, CustomLayoutSettings> {
+    /// Mirrors the command horizontally within a layout of the given width, for right to left layouts.
+    pub(crate) fn mirror(&mut self, layout_width: f32) {
+        match self {
+            RenderCommand::None | RenderCommand::ScissorEnd => {}
+            RenderCommand::Rectangle(rectangle) => {
+                rectangle.bounding_box.mirror(layout_width);
+                rectangle.corner_radii.mirror();
+            }
+            RenderCommand::Border(border) => {
+                border.bounding_box.mirror(layout_width);
+                border.corner_radii.mirror();
+                border.width.mirror();
+            }
+            RenderCommand::Text(text) => text.bounding_box.mirror(layout_width),
+            RenderCommand::ScissorStart(bounding_box) => bounding_box.mirror(layout_width),
+            RenderCommand::Image(image) => {
+                image.bounding_box.mirror(layout_width);
+                image.corner_radii.mirror();
+            }
+            RenderCommand::Custom(custom) => {
+                custom.bounding_box.mirror(layout_width);
+                custom.corner_radii.mirror();
+            }
+        }
+    }
+}
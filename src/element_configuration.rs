@@ -1,188 +1,37 @@
-use std::os::raw::c_void;
-
 use crate::bindings::*;
+use crate::{CustomElementHandle, ElementId, Floating, ImageHandle, LayoutSettingsHandle, Sizing, SizingError, Style};
 
 #[derive(Default, Clone, Copy)]
 pub struct ElementConfiguration{
-    decleration: Clay_ElementDeclaration
+    decleration: Clay_ElementDeclaration,
+    image_dimensions: Option<Vec2>,
+    width_error: Option<SizingError>,
+    height_error: Option<SizingError>,
+    managed_scroll: bool,
+    clip_only: bool,
+    image: Option<ImageHandle>,
+    custom_element: Option<CustomElementHandle>,
+    custom_layout_settings: Option<LayoutSettingsHandle>,
 }
 
 impl ElementConfiguration{
     pub fn new() -> Self {
         Self::default()
     }
-    pub fn id(&mut self, label: &str) -> &mut Self {
-        self.decleration.id = unsafe {
-            Clay__HashString(
-                Clay_String { 
-                    isStaticallyAllocated: true, 
-                    length: label.len() as i32, 
-                    chars: label.as_ptr() as *const _
-                },
-                0,
-                0
-            ) 
-        };
-        self
-    }
-    pub fn grow_all(&mut self) -> &mut Self {
-        self.decleration.layout.sizing.width = Clay_SizingAxis {
-            type_: Clay__SizingType::CLAY__SIZING_TYPE_GROW,
-            size: Clay_SizingAxis__bindgen_ty_1 {
-                minMax: Clay_SizingMinMax { min: 0.0, max: f32::MAX },
-            },
-        };
-        self.decleration.layout.sizing.height = Clay_SizingAxis {
-            type_: Clay__SizingType::CLAY__SIZING_TYPE_GROW,
-            size: Clay_SizingAxis__bindgen_ty_1 {
-                minMax: Clay_SizingMinMax { min: 0.0, max: f32::MAX },
-            },
-        };
-        self
-    }
-    pub fn x_grow(&mut self) -> &mut Self {
-        self.decleration.layout.sizing.width = Clay_SizingAxis {
-            type_: Clay__SizingType::CLAY__SIZING_TYPE_GROW,
-            size: Clay_SizingAxis__bindgen_ty_1 {
-                minMax: Clay_SizingMinMax { min: 0.0, max: f32::MAX },
-            },
-        };
-        self
-    }
-    pub fn x_grow_min(&mut self, min: f32) -> &mut Self {
-        self.decleration.layout.sizing.width = Clay_SizingAxis {
-            type_: Clay__SizingType::CLAY__SIZING_TYPE_GROW,
-            size: Clay_SizingAxis__bindgen_ty_1 {
-                minMax: Clay_SizingMinMax { min, max: f32::MAX },
-            },
-        };
-        self
-    }
-    pub fn x_grow_min_max(&mut self, min: f32, max: f32) -> &mut Self {
-        self.decleration.layout.sizing.width = Clay_SizingAxis {
-            type_: Clay__SizingType::CLAY__SIZING_TYPE_GROW,
-            size: Clay_SizingAxis__bindgen_ty_1 {
-                minMax: Clay_SizingMinMax { min, max },
-            },
-        };
-        self
-    }
-    pub fn y_grow(&mut self) -> &mut Self {
-        //println!("y-grow-function");
-        self.decleration.layout.sizing.height = Clay_SizingAxis {
-            type_: Clay__SizingType::CLAY__SIZING_TYPE_GROW,
-            size: Clay_SizingAxis__bindgen_ty_1 {
-                minMax: Clay_SizingMinMax { min: 0.0, max: f32::MAX },
-            },
-        };
-        self
-    }
-    pub fn y_grow_min(&mut self, min: f32) -> &mut Self {
-        self.decleration.layout.sizing.height = Clay_SizingAxis {
-            type_: Clay__SizingType::CLAY__SIZING_TYPE_GROW,
-            size: Clay_SizingAxis__bindgen_ty_1 {
-                minMax: Clay_SizingMinMax { min, max: f32::MAX },
-            },
-        };
-        self
-    }
-    pub fn y_grow_min_max(&mut self, min: f32, max: f32) -> &mut Self {
-        self.decleration.layout.sizing.height = Clay_SizingAxis {
-            type_: Clay__SizingType::CLAY__SIZING_TYPE_GROW,
-            size: Clay_SizingAxis__bindgen_ty_1 {
-                minMax: Clay_SizingMinMax { min, max },
-            },
-        };
-        self
-    }
-    pub fn x_fit(&mut self) -> &mut Self {
-        self.decleration.layout.sizing.width = Clay_SizingAxis {
-            type_: Clay__SizingType::CLAY__SIZING_TYPE_FIT,
-            size: Clay_SizingAxis__bindgen_ty_1 {
-                minMax: Clay_SizingMinMax { min: 0.0, max: f32::MAX },
-            },
-        };
-        self
-    }
-    pub fn x_fit_min(&mut self, min: f32) -> &mut Self {
-        self.decleration.layout.sizing.width = Clay_SizingAxis {
-            type_: Clay__SizingType::CLAY__SIZING_TYPE_FIT,
-            size: Clay_SizingAxis__bindgen_ty_1 {
-                minMax: Clay_SizingMinMax { min, max: f32::MAX },
-            },
-        };
-        self
-    }
-    pub fn x_fit_min_max(&mut self, min: f32,max: f32) -> &mut Self {
-        self.decleration.layout.sizing.width = Clay_SizingAxis {
-            type_: Clay__SizingType::CLAY__SIZING_TYPE_FIT,
-            size: Clay_SizingAxis__bindgen_ty_1 {
-                minMax: Clay_SizingMinMax { min, max },
-            },
-        };
-        self
-    }
-    pub fn y_fit(&mut self) -> &mut Self {
-        self.decleration.layout.sizing.height = Clay_SizingAxis {
-            type_: Clay__SizingType::CLAY__SIZING_TYPE_FIT,
-            size: Clay_SizingAxis__bindgen_ty_1 {
-                minMax: Clay_SizingMinMax { min: 0.0, max: f32::MAX },
-            },
-        };
-        self
-    }
-    pub fn y_fit_min(&mut self, min: f32) -> &mut Self {
-        self.decleration.layout.sizing.height = Clay_SizingAxis {
-            type_: Clay__SizingType::CLAY__SIZING_TYPE_FIT,
-            size: Clay_SizingAxis__bindgen_ty_1 {
-                minMax: Clay_SizingMinMax { min, max: f32::MAX },
-            },
-        };
-        self
-    }
-    pub fn y_fit_min_max(&mut self, min: f32,max: f32) -> &mut Self {
-        self.decleration.layout.sizing.height = Clay_SizingAxis {
-            type_: Clay__SizingType::CLAY__SIZING_TYPE_FIT,
-            size: Clay_SizingAxis__bindgen_ty_1 {
-                minMax: Clay_SizingMinMax { min, max },
-            },
-        };
-        self
-    }
-    pub fn x_fixed(&mut self, size:f32) -> &mut Self {
-        self.decleration.layout.sizing.width = Clay_SizingAxis {
-            type_: Clay__SizingType::CLAY__SIZING_TYPE_FIXED,
-            size: Clay_SizingAxis__bindgen_ty_1 {
-                minMax: Clay_SizingMinMax { min: size, max: size },
-            },
-        };
+    pub fn id(&mut self, id: impl Into<ElementId>) -> &mut Self {
+        self.decleration.id = id.into().into();
         self
     }
-    pub fn y_fixed(&mut self, size:f32) -> &mut Self {
-        self.decleration.layout.sizing.height = Clay_SizingAxis {
-            type_: Clay__SizingType::CLAY__SIZING_TYPE_FIXED,
-            size: Clay_SizingAxis__bindgen_ty_1 {
-                minMax: Clay_SizingMinMax { min: size, max: size },
-            },
-        };
+    /// Sets how the width of the element is sized.
+    pub fn width(&mut self, sizing: Sizing) -> &mut Self {
+        self.width_error = sizing.validate().err();
+        self.decleration.layout.sizing.width = sizing.into();
         self
     }
-    pub fn x_percent(&mut self, percent:f32) -> &mut Self {
-        self.decleration.layout.sizing.width = Clay_SizingAxis {
-            type_: Clay__SizingType::CLAY__SIZING_TYPE_PERCENT,
-            size: Clay_SizingAxis__bindgen_ty_1 {
-                minMax: Clay_SizingMinMax { min: percent, max: percent },
-            },
-        };
-        self
-    }
-    pub fn y_percent(&mut self, percent:f32) -> &mut Self {
-        self.decleration.layout.sizing.height = Clay_SizingAxis {
-            type_: Clay__SizingType::CLAY__SIZING_TYPE_PERCENT,
-            size: Clay_SizingAxis__bindgen_ty_1 {
-                minMax: Clay_SizingMinMax { min: percent, max: percent },
-            },
-        };
+    /// Sets how the height of the element is sized.
+    pub fn height(&mut self, sizing: Sizing) -> &mut Self {
+        self.height_error = sizing.validate().err();
+        self.decleration.layout.sizing.height = sizing.into();
         self
     }
     pub fn padding_all(&mut self, amount: u16) -> &mut Self {
@@ -301,125 +150,136 @@ impl ElementConfiguration{
     }
     pub fn scroll(&mut self, vertical: bool, horizontal: bool, child_offset: Clay_Vector2) -> &mut Self {
         self.decleration.clip = Clay_ClipElementConfig { horizontal, vertical, childOffset: child_offset };
+        self.managed_scroll = false;
+        self.clip_only = false;
         self
     }
-    pub fn floating(&mut self) -> &mut Self {
-        self.decleration.floating = Clay_FloatingElementConfig::default();
-        self.decleration.floating.attachTo = Clay_FloatingAttachToElement::CLAY_ATTACH_TO_PARENT;
-        self
-    }
-    pub fn floating_offset(&mut self, x:f32, y:f32) -> &mut Self{
-        self.decleration.floating.offset = Clay_Vector2 { x, y };
-        self
-    }
-    pub fn floating_dimensions(&mut self, width:f32, height:f32) -> &mut Self{
-        self.decleration.floating.expand = Clay_Dimensions {width, height};
-        self
-    }
-    pub fn floating_z_index(&mut self, z:i16) -> &mut Self {
-        self.decleration.floating.zIndex = z;
-        self
-    }
-    pub fn floating_attach_to_parent_at_top_left(&mut self) -> &mut Self {
-        self.decleration.floating.attachPoints.parent = Clay_FloatingAttachPointType::CLAY_ATTACH_POINT_LEFT_TOP;
-        self
-    }
-    pub fn floating_attach_to_parent_at_center_left(&mut self) -> &mut Self {
-        self.decleration.floating.attachPoints.parent = Clay_FloatingAttachPointType::CLAY_ATTACH_POINT_LEFT_CENTER;
-        self
-    }
-    pub fn floating_attach_to_parent_at_bottom_left(&mut self) -> &mut Self {
-        self.decleration.floating.attachPoints.parent = Clay_FloatingAttachPointType::CLAY_ATTACH_POINT_LEFT_BOTTOM;
-        self
-    }
-    pub fn floating_attach_to_parent_at_top_center(&mut self) -> &mut Self {
-        self.decleration.floating.attachPoints.parent = Clay_FloatingAttachPointType::CLAY_ATTACH_POINT_CENTER_TOP;
+    /// Clips the children along the given axes and scrolls them by the element's own scroll position,
+    /// which the engine looks up while configuring the element.
+    pub fn scroll_container(&mut self, vertical: bool, horizontal: bool) -> &mut Self {
+        self.decleration.clip = Clay_ClipElementConfig { horizontal, vertical, childOffset: Clay_Vector2::default() };
+        self.managed_scroll = true;
+        self.clip_only = false;
         self
     }
-    pub fn floating_attach_to_parent_at_center(&mut self) -> &mut Self {
-        self.decleration.floating.attachPoints.parent = Clay_FloatingAttachPointType::CLAY_ATTACH_POINT_CENTER_CENTER;
+    /// Clips the children along the given axes without ever scrolling them.
+    /// Scrolling over the element scrolls the scroll container around it instead.
+    pub fn clip(&mut self, vertical: bool, horizontal: bool) -> &mut Self {
+        self.decleration.clip = Clay_ClipElementConfig { horizontal, vertical, childOffset: Clay_Vector2::default() };
+        self.managed_scroll = false;
+        self.clip_only = true;
         self
     }
-    pub fn floating_attach_to_parent_at_bottom_center(&mut self) -> &mut Self {
-        self.decleration.floating.attachPoints.parent = Clay_FloatingAttachPointType::CLAY_ATTACH_POINT_CENTER_BOTTOM;
+    /// Takes the element out of the normal flow and positions it on top of the layout.
+    pub fn floating(&mut self, floating: Floating) -> &mut Self {
+        self.decleration.floating = floating.into();
         self
     }
-    pub fn floating_attach_to_parent_at_top_right(&mut self) -> &mut Self {
-        self.decleration.floating.attachPoints.parent = Clay_FloatingAttachPointType::CLAY_ATTACH_POINT_RIGHT_TOP;
+    /// Draws the image stored with `LayoutEngine::store_image`.
+    pub fn image(&mut self, image: ImageHandle) -> &mut Self {
+        self.image = Some(image);
         self
     }
-    pub fn floating_attach_to_parent_at_center_right(&mut self) -> &mut Self {
-        self.decleration.floating.attachPoints.parent = Clay_FloatingAttachPointType::CLAY_ATTACH_POINT_RIGHT_CENTER;
+    /// Sets the natural size of the image.
+    /// The element keeps the aspect ratio of the image, and the size is passed on to the `Image` render command.
+    pub fn image_dimensions(&mut self, width: f32, height: f32) -> &mut Self {
+        self.image_dimensions = Some(Vec2 { x: width, y: height });
+        if height > 0.0 {
+            self.decleration.aspectRatio.aspectRatio = width / height;
+        }
         self
     }
-    pub fn floating_attach_to_parent_at_bottom_right(&mut self) -> &mut Self {
-        self.decleration.floating.attachPoints.parent = Clay_FloatingAttachPointType::CLAY_ATTACH_POINT_RIGHT_BOTTOM;
+    /// Keeps the width of the element at `ratio` times its height.
+    pub fn aspect_ratio(&mut self, ratio: f32) -> &mut Self {
+        self.decleration.aspectRatio.aspectRatio = ratio;
         self
     }
-    pub fn floating_attach_element_at_top_left(&mut self) -> &mut Self {
-        self.decleration.floating.attachPoints.element =  Clay_FloatingAttachPointType::CLAY_ATTACH_POINT_LEFT_TOP;
+    /// Makes this a custom element drawing the data stored with `LayoutEngine::store_custom_element`.
+    pub fn custom_element(&mut self, custom_element: CustomElementHandle) -> &mut Self{
+        self.custom_element = Some(custom_element);
         self
     }
-    pub fn floating_attach_element_at_center_left(&mut self) -> &mut Self {
-        self.decleration.floating.attachPoints.element = Clay_FloatingAttachPointType::CLAY_ATTACH_POINT_LEFT_CENTER;
+    /// Passes the settings stored with `LayoutEngine::store_layout_settings` on to the element's render commands.
+    pub fn custom_layout_settings(&mut self, custom_layout_settings: LayoutSettingsHandle) -> &mut Self{
+        self.custom_layout_settings = Some(custom_layout_settings);
         self
     }
-    pub fn floating_attach_element_at_bottom_left(&mut self) -> &mut Self {
-        self.decleration.floating.attachPoints.element = Clay_FloatingAttachPointType::CLAY_ATTACH_POINT_LEFT_BOTTOM;
+    /// Applies every setting the style sets and leaves the others as they are.
+    pub fn apply(&mut self, style: &Style) -> &mut Self {
+        if let Some(sizing) = style.width { self.width(sizing); }
+        if let Some(sizing) = style.height { self.height(sizing); }
+        if let Some(amount) = style.padding_top { self.padding_top(amount); }
+        if let Some(amount) = style.padding_bottom { self.padding_bottom(amount); }
+        if let Some(amount) = style.padding_left { self.padding_left(amount); }
+        if let Some(amount) = style.padding_right { self.padding_right(amount); }
+        if let Some(amount) = style.child_gap { self.child_gap(amount); }
+        if let Some(top_to_bottom) = style.top_to_bottom { self.direction(top_to_bottom); }
+        if let Some(alignment) = style.align_children_x { self.decleration.layout.childAlignment.x = alignment; }
+        if let Some(alignment) = style.align_children_y { self.decleration.layout.childAlignment.y = alignment; }
+        if let Some(color) = style.color { self.color(color); }
+        if let Some(radius) = style.radius_top_left { self.radius_top_left(radius); }
+        if let Some(radius) = style.radius_top_right { self.radius_top_right(radius); }
+        if let Some(radius) = style.radius_bottom_left { self.radius_bottom_left(radius); }
+        if let Some(radius) = style.radius_bottom_right { self.radius_bottom_right(radius); }
+        if let Some(color) = style.border_color { self.border_color(color); }
+        if let Some(width) = style.border_top { self.border_top(width); }
+        if let Some(width) = style.border_bottom { self.border_bottom(width); }
+        if let Some(width) = style.border_left { self.border_left(width); }
+        if let Some(width) = style.border_right { self.border_right(width); }
+        if let Some(width) = style.border_between_children { self.border_between_children(width); }
+        if let Some(ratio) = style.aspect_ratio { self.aspect_ratio(ratio); }
         self
     }
-    pub fn floating_attach_element_at_top_center(&mut self) -> &mut Self {
-        self.decleration.floating.attachPoints.element = Clay_FloatingAttachPointType::CLAY_ATTACH_POINT_CENTER_TOP;
-        self
+    pub(crate) fn is_managed_scroll_container(&self) -> bool {
+        self.managed_scroll
     }
-    pub fn floating_attach_element_at_center(&mut self) -> &mut Self {
-        self.decleration.floating.attachPoints.element = Clay_FloatingAttachPointType::CLAY_ATTACH_POINT_CENTER_CENTER;
-        self
+    pub(crate) fn is_clip_only(&self) -> bool {
+        self.clip_only
     }
-    pub fn floating_attach_element_at_bottom_center(&mut self) -> &mut Self {
-        self.decleration.floating.attachPoints.element = Clay_FloatingAttachPointType::CLAY_ATTACH_POINT_CENTER_BOTTOM;
-        self
+    pub(crate) fn get_clip(&self) -> Clay_ClipElementConfig {
+        self.decleration.clip
     }
-    pub fn floating_attach_element_at_top_right(&mut self) -> &mut Self {
-        self.decleration.floating.attachPoints.element = Clay_FloatingAttachPointType::CLAY_ATTACH_POINT_RIGHT_TOP;
-        self
+    pub(crate) fn get_id(&self) -> u32 {
+        self.decleration.id.id
     }
-    pub fn floating_attach_element_at_center_right(&mut self) -> &mut Self {
-        self.decleration.floating.attachPoints.element = Clay_FloatingAttachPointType::CLAY_ATTACH_POINT_RIGHT_CENTER;
-        self
+    pub(crate) fn get_layout(&self) -> Clay_LayoutConfig {
+        self.decleration.layout
     }
-    pub fn floating_attach_element_at_bottom_right(&mut self) -> &mut Self {
-        self.decleration.floating.attachPoints.element = Clay_FloatingAttachPointType::CLAY_ATTACH_POINT_RIGHT_BOTTOM;
-        self
+    pub(crate) fn set_layout(&mut self, layout: Clay_LayoutConfig) {
+        self.decleration.layout = layout;
     }
-    pub fn floating_pointer_pass_through(&mut self) -> &mut Self {
-        self.decleration.floating.pointerCaptureMode = Clay_PointerCaptureMode::CLAY_POINTER_CAPTURE_MODE_PASSTHROUGH;
-        self
+    pub(crate) fn get_image(&self) -> Option<ImageHandle> {
+        self.image
     }
-    pub fn floating_attach_to_element(&mut self, element_id: u32) -> &mut Self {
-        self.decleration.floating.parentId = element_id;
-        self.decleration.floating.attachTo = Clay_FloatingAttachToElement::CLAY_ATTACH_TO_ELEMENT_WITH_ID;
-        self
+    pub(crate) fn get_custom_element(&self) -> Option<CustomElementHandle> {
+        self.custom_element
     }
-    pub fn floating_attach_to_root(&mut self) -> &mut Self {
-        self.decleration.floating.attachTo = Clay_FloatingAttachToElement::CLAY_ATTACH_TO_ROOT;
-        self
+    pub(crate) fn get_custom_layout_settings(&self) -> Option<LayoutSettingsHandle> {
+        self.custom_layout_settings
     }
-    pub fn image<'render_pass, ImageElementData>(&mut self, image: &'render_pass ImageElementData) -> &mut Self {
-        self.decleration.image.imageData = image as *const ImageElementData as *mut c_void;
-        self
-    }
-    pub fn custom_element<'render_pass, CustomElementData>(&mut self, custom_element_data: &'render_pass CustomElementData) -> &mut Self{
-        self.decleration.custom.customData = custom_element_data as *const CustomElementData as *mut c_void;
-        self
-    }
-    pub fn custom_layout_settings<'render_pass, CustomLayoutSettings>(&mut self, custom_layout_settings: &'render_pass CustomLayoutSettings) -> &mut Self{
-        self.decleration.userData = custom_layout_settings as *const CustomLayoutSettings as *mut c_void;
-        self
+    pub(crate) fn get_image_dimensions(&self) -> Option<Vec2> {
+        self.image_dimensions
     }
     pub fn parse(&mut self){}
+    /// Finishes the configuration.
+    /// Panics if it was given an invalid `Sizing`; use `try_end` to handle that instead.
     pub fn end(self) -> Self {
-        self
+        match self.try_end() {
+            Ok(config) => config,
+            Err(error) => panic!("Invalid element sizing: {error}"),
+        }
+    }
+    /// Finishes the configuration, or returns why its width or height is invalid.
+    pub fn try_end(self) -> Result<Self, SizingError> {
+        match self.sizing_error() {
+            Some(error) => Err(error),
+            None => Ok(self),
+        }
+    }
+    /// The error of the current width, or else of the current height.
+    /// Setting a valid size for an axis clears the error of an earlier invalid one.
+    pub(crate) fn sizing_error(&self) -> Option<SizingError> {
+        self.width_error.or(self.height_error)
     }
 }
 
@@ -0,0 +1,97 @@
+use std::hash::{Hash, Hasher};
+
+use crate::bindings::*;
+
+/// Identifies an element across frames.
+///
+/// Ids are hashed from a name, so the same name always results in the same id.
+/// Repeated elements like the rows of a list are told apart with an index,
+/// and elements that only have to be unique within their parent are hashed together with the parent's id.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct ElementId {
+    /// The hashed id clay uses for the element.
+    pub id: u32,
+    /// The index the name was hashed with.
+    pub offset: u32,
+    /// The hash of the name without the index.
+    pub base_id: u32,
+}
+
+impl ElementId {
+    /// The id of the element with the given name.
+    pub fn new(name: &str) -> Self {
+        hash(name, 0, 0)
+    }
+
+    /// The id of one of several elements sharing a name, like the rows of a list.
+    pub fn indexed(name: &str, index: u32) -> Self {
+        hash(name, index, 0)
+    }
+
+    /// The id of an element whose name only has to be unique among the children of `parent`.
+    pub fn local(parent: impl Into<ElementId>, name: &str) -> Self {
+        hash(name, 0, parent.into().id)
+    }
+
+    /// The id of an item without an id of its own in a container the engine arranges, by its position.
+    pub(crate) fn container_item(container: u32, index: u32) -> Self {
+        hash("Telera__ContainerItem", index, container)
+    }
+}
+
+fn hash(name: &str, offset: u32, seed: u32) -> ElementId {
+    unsafe {
+        Clay__HashString(
+            Clay_String {
+                isStaticallyAllocated: false,
+                length: name.len() as i32,
+                chars: name.as_ptr() as *const _
+            },
+            offset,
+            seed
+        )
+    }.into()
+}
+
+impl PartialEq for ElementId {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+impl Eq for ElementId {}
+
+impl Hash for ElementId {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.id.hash(state);
+    }
+}
+
+impl From<&str> for ElementId {
+    fn from(name: &str) -> Self {
+        ElementId::new(name)
+    }
+}
+
+impl From<u32> for ElementId {
+    fn from(id: u32) -> Self {
+        ElementId { id, offset: 0, base_id: id }
+    }
+}
+
+impl From<Clay_ElementId> for ElementId {
+    fn from(value: Clay_ElementId) -> Self {
+        ElementId { id: value.id, offset: value.offset, base_id: value.baseId }
+    }
+}
+
+impl From<ElementId> for Clay_ElementId {
+    fn from(value: ElementId) -> Self {
+        Clay_ElementId {
+            id: value.id,
+            offset: value.offset,
+            baseId: value.base_id,
+            ..Default::default()
+        }
+    }
+}
@@ -0,0 +1,166 @@
+use crate::bindings::*;
+use crate::ElementId;
+
+/// A point on the edge or in the center of an element.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AttachPoint {
+    #[default]
+    TopLeft,
+    CenterLeft,
+    BottomLeft,
+    TopCenter,
+    Center,
+    BottomCenter,
+    TopRight,
+    CenterRight,
+    BottomRight,
+}
+
+impl From<AttachPoint> for Clay_FloatingAttachPointType {
+    fn from(value: AttachPoint) -> Self {
+        match value {
+            AttachPoint::TopLeft => Clay_FloatingAttachPointType::CLAY_ATTACH_POINT_LEFT_TOP,
+            AttachPoint::CenterLeft => Clay_FloatingAttachPointType::CLAY_ATTACH_POINT_LEFT_CENTER,
+            AttachPoint::BottomLeft => Clay_FloatingAttachPointType::CLAY_ATTACH_POINT_LEFT_BOTTOM,
+            AttachPoint::TopCenter => Clay_FloatingAttachPointType::CLAY_ATTACH_POINT_CENTER_TOP,
+            AttachPoint::Center => Clay_FloatingAttachPointType::CLAY_ATTACH_POINT_CENTER_CENTER,
+            AttachPoint::BottomCenter => Clay_FloatingAttachPointType::CLAY_ATTACH_POINT_CENTER_BOTTOM,
+            AttachPoint::TopRight => Clay_FloatingAttachPointType::CLAY_ATTACH_POINT_RIGHT_TOP,
+            AttachPoint::CenterRight => Clay_FloatingAttachPointType::CLAY_ATTACH_POINT_RIGHT_CENTER,
+            AttachPoint::BottomRight => Clay_FloatingAttachPointType::CLAY_ATTACH_POINT_RIGHT_BOTTOM,
+        }
+    }
+}
+
+/// The element a floating element is positioned relative to.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AttachTo {
+    /// The element the floating element is declared in.
+    #[default]
+    Parent,
+    /// Any element in the layout.
+    Element(ElementId),
+    /// The root of the layout.
+    Root,
+}
+
+/// Whether a floating element is clipped.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FloatingClip {
+    /// The floating element is drawn in full.
+    #[default]
+    None,
+    /// The floating element is clipped by the same clip region as the element it is attached to.
+    AttachedParent,
+}
+
+/// What happens to pointer events over a floating element.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PointerCapture {
+    /// Elements below the floating element do not see the pointer.
+    #[default]
+    Capture,
+    /// Elements below the floating element see the pointer as well.
+    PassThrough,
+}
+
+/// Positions an element on top of the layout, outside of the normal flow.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct Floating {
+    /// Moves the element away from its attach point.
+    pub offset: Vec2,
+    /// Grows the element beyond its size on each side.
+    pub expand: Vec2,
+    /// Floating elements with a higher z index are drawn above those with a lower one.
+    pub z_index: i16,
+    /// The point on the floating element that is placed on the parent's attach point.
+    pub element_attach_point: AttachPoint,
+    /// The point on the parent the floating element is placed on.
+    pub parent_attach_point: AttachPoint,
+    pub attach_to: AttachTo,
+    pub clip: FloatingClip,
+    pub pointer_capture: PointerCapture,
+}
+
+impl Floating {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the offset from the attach point.
+    pub fn offset(&mut self, x: f32, y: f32) -> &mut Self {
+        self.offset = Vec2 { x, y };
+        self
+    }
+
+    /// Sets how far the element grows beyond its size on each side.
+    pub fn expand(&mut self, width: f32, height: f32) -> &mut Self {
+        self.expand = Vec2 { x: width, y: height };
+        self
+    }
+
+    /// Sets the z index.
+    pub fn z_index(&mut self, z_index: i16) -> &mut Self {
+        self.z_index = z_index;
+        self
+    }
+
+    /// Places the `element` point of the floating element on the `parent` point of what it is attached to.
+    pub fn attach_points(&mut self, element: AttachPoint, parent: AttachPoint) -> &mut Self {
+        self.element_attach_point = element;
+        self.parent_attach_point = parent;
+        self
+    }
+
+    /// Sets what the element is attached to.
+    pub fn attach_to(&mut self, attach_to: AttachTo) -> &mut Self {
+        self.attach_to = attach_to;
+        self
+    }
+
+    /// Sets whether the element is clipped like the element it is attached to.
+    pub fn clip(&mut self, clip: FloatingClip) -> &mut Self {
+        self.clip = clip;
+        self
+    }
+
+    /// Sets whether elements below see the pointer.
+    pub fn pointer_capture(&mut self, pointer_capture: PointerCapture) -> &mut Self {
+        self.pointer_capture = pointer_capture;
+        self
+    }
+
+    pub fn end(self) -> Self {
+        self
+    }
+}
+
+impl From<Floating> for Clay_FloatingElementConfig {
+    fn from(value: Floating) -> Self {
+        let (attach_to, parent_id) = match value.attach_to {
+            AttachTo::Parent => (Clay_FloatingAttachToElement::CLAY_ATTACH_TO_PARENT, 0),
+            AttachTo::Element(id) => (Clay_FloatingAttachToElement::CLAY_ATTACH_TO_ELEMENT_WITH_ID, id.id),
+            AttachTo::Root => (Clay_FloatingAttachToElement::CLAY_ATTACH_TO_ROOT, 0),
+        };
+
+        Clay_FloatingElementConfig {
+            offset: Clay_Vector2 { x: value.offset.x, y: value.offset.y },
+            expand: value.expand.into(),
+            parentId: parent_id,
+            zIndex: value.z_index,
+            attachPoints: Clay_FloatingAttachPoints {
+                element: value.element_attach_point.into(),
+                parent: value.parent_attach_point.into(),
+            },
+            pointerCaptureMode: match value.pointer_capture {
+                PointerCapture::Capture => Clay_PointerCaptureMode::CLAY_POINTER_CAPTURE_MODE_CAPTURE,
+                PointerCapture::PassThrough => Clay_PointerCaptureMode::CLAY_POINTER_CAPTURE_MODE_PASSTHROUGH,
+            },
+            attachTo: attach_to,
+            clipTo: match value.clip {
+                FloatingClip::None => Clay_FloatingClipToElement::CLAY_CLIP_TO_NONE,
+                FloatingClip::AttachedParent => Clay_FloatingClipToElement::CLAY_CLIP_TO_ATTACHED_PARENT,
+            },
+        }
+    }
+}
@@ -0,0 +1,147 @@
+use std::collections::HashMap;
+use std::fmt::{Debug, Display};
+
+use ttf_parser::{Face, FaceParsingError, GlyphId};
+
+use crate::{MeasureText, TextConfig, Vec2};
+
+/// Measures text with the metrics of TrueType and OpenType fonts, keyed by [`TextConfig::font_id`].
+///
+/// Widths are the sum of the glyph advances, so there is no kerning or shaping.
+/// Text in a font that was never loaded is measured with font 0.
+#[derive(Default, Clone)]
+pub struct FontRegistry {
+    fonts: HashMap<u16, FontData>,
+}
+
+impl Debug for FontRegistry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_map().entries(&self.fonts).finish()
+    }
+}
+
+/// The metrics of a font the registry measures with, read once when it is loaded.
+#[derive(Clone)]
+struct FontData {
+    units_per_em: u16,
+    ascender: i16,
+    descender: i16,
+    line_gap: i16,
+    /// The advance of every character the font maps to a glyph.
+    advances: HashMap<char, u16>,
+    /// The advance of the glyph shown for characters the font does not map.
+    missing_advance: u16,
+}
+
+impl Debug for FontData {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FontData")
+            .field("units_per_em", &self.units_per_em)
+            .field("ascender", &self.ascender)
+            .field("descender", &self.descender)
+            .field("line_gap", &self.line_gap)
+            .field("characters", &self.advances.len())
+            .finish()
+    }
+}
+
+impl FontData {
+    fn new(face: &Face<'_>) -> Self {
+        let mut advances = HashMap::new();
+        let subtables = face.tables().cmap.iter().flat_map(|cmap| cmap.subtables);
+        for subtable in subtables.filter(|subtable| subtable.is_unicode()) {
+            subtable.codepoints(|codepoint| {
+                if let Some(character) = char::from_u32(codepoint)
+                    && let Some(glyph) = face.glyph_index(character)
+                {
+                    advances.insert(character, face.glyph_hor_advance(glyph).unwrap_or_default());
+                }
+            });
+        }
+        Self {
+            units_per_em: face.units_per_em(),
+            ascender: face.ascender(),
+            descender: face.descender(),
+            line_gap: face.line_gap(),
+            advances,
+            missing_advance: face.glyph_hor_advance(GlyphId(0)).unwrap_or_default(),
+        }
+    }
+
+    fn advance(&self, character: char) -> u16 {
+        self.advances.get(&character).copied().unwrap_or(self.missing_advance)
+    }
+}
+
+impl FontRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Loads a font file, replacing any font already loaded for `font_id`.
+    pub fn load(&mut self, font_id: u16, data: impl Into<Vec<u8>>) -> Result<(), FontError> {
+        self.load_from_collection(font_id, data, 0)
+    }
+
+    /// Loads the font at `index` of a font collection file, replacing any font already loaded for `font_id`.
+    /// Only the metrics text is measured with are kept, not the file.
+    pub fn load_from_collection(&mut self, font_id: u16, data: impl Into<Vec<u8>>, index: u32) -> Result<(), FontError> {
+        let data = data.into();
+        let face = Face::parse(&data, index).map_err(FontError)?;
+        self.fonts.insert(font_id, FontData::new(&face));
+        Ok(())
+    }
+
+    /// Forgets the font loaded for `font_id`.
+    pub fn remove(&mut self, font_id: u16) -> bool {
+        self.fonts.remove(&font_id).is_some()
+    }
+
+    pub fn contains(&self, font_id: u16) -> bool {
+        self.fonts.contains_key(&font_id)
+    }
+}
+
+impl MeasureText for FontRegistry {
+    fn measure_text(&mut self, text: &str, text_config: TextConfig) -> Vec2 {
+        let Some(font) = self.fonts.get(&text_config.font_id).or_else(|| self.fonts.get(&0)) else {
+            return Vec2 { x: 0.0, y: text_config.line_height as f32 };
+        };
+        let scale = text_config.font_size as f32 / font.units_per_em as f32;
+
+        let mut lines = 0;
+        let mut width = 0.0f32;
+        for line in text.split('\n') {
+            let mut line_width = 0.0;
+            let mut characters = 0;
+            for character in line.chars() {
+                line_width += font.advance(character) as f32 * scale;
+                characters += 1;
+            }
+            if characters > 1 {
+                line_width += (characters - 1) as f32 * text_config.letter_spacing as f32;
+            }
+            width = width.max(line_width);
+            lines += 1;
+        }
+
+        let line_height = if text_config.line_height > 0 {
+            text_config.line_height as f32
+        } else {
+            (font.ascender - font.descender + font.line_gap) as f32 * scale
+        };
+        Vec2 { x: width, y: line_height * lines as f32 }
+    }
+}
+
+/// Why a font file could not be loaded.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FontError(FaceParsingError);
+
+impl Display for FontError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "the font could not be parsed: {}", self.0)
+    }
+}
+
+impl std::error::Error for FontError {}
@@ -0,0 +1,162 @@
+use std::borrow::Cow;
+use std::fmt::Debug;
+
+use crate::sizing::check_size;
+use crate::wrap::ContainerItem;
+use crate::{ElementConfiguration, LayoutEngine, SizingError, TextConfig};
+
+/// How a row or column of a grid is sized.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Track {
+    /// A fixed size.
+    Fixed(f32),
+    /// As large as the largest cell in the track that does not span other tracks.
+    Fit,
+    /// Shares the space left over with the other growing tracks, in proportion to the weight.
+    /// Along an axis the grid fits its content on, it fits its cells instead.
+    Grow(f32),
+    /// A fraction of the grid's size, from 0.0 to 1.0.
+    /// Along an axis the grid fits its content on, it fits its cells instead.
+    Percent(f32),
+}
+
+impl Track {
+    /// Checks that sizes and weights are not negative and percentages are between 0.0 and 1.0.
+    pub fn validate(&self) -> Result<(), SizingError> {
+        match *self {
+            Track::Fixed(size) | Track::Grow(size) => check_size(size),
+            Track::Fit => Ok(()),
+            Track::Percent(percent) => {
+                if percent.is_nan() || !(0.0..=1.0).contains(&percent) {
+                    return Err(SizingError::PercentOutOfRange(percent));
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl Default for Track {
+    fn default() -> Self {
+        Track::Grow(1.0)
+    }
+}
+
+/// The tracks and gaps of a grid, see [`LayoutEngine::grid`].
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Grid {
+    pub columns: Vec<Track>,
+    pub rows: Vec<Track>,
+    /// The space between columns.
+    pub column_gap: u16,
+    /// The space between rows.
+    pub row_gap: u16,
+}
+
+impl Grid {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn columns(mut self, columns: &[Track]) -> Self {
+        self.columns = columns.to_vec();
+        self
+    }
+
+    pub fn rows(mut self, rows: &[Track]) -> Self {
+        self.rows = rows.to_vec();
+        self
+    }
+
+    /// Sets the space between columns and between rows.
+    pub fn gaps(mut self, column_gap: u16, row_gap: u16) -> Self {
+        self.column_gap = column_gap;
+        self.row_gap = row_gap;
+        self
+    }
+
+    pub fn end(self) -> Self {
+        self
+    }
+}
+
+/// Where a cell is placed in a grid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Cell {
+    pub row: usize,
+    pub column: usize,
+    /// The number of rows the cell covers.
+    pub row_span: usize,
+    /// The number of columns the cell covers.
+    pub column_span: usize,
+}
+
+impl Cell {
+    /// A cell covering a single track in both directions.
+    pub fn at(row: usize, column: usize) -> Self {
+        Cell { row, column, row_span: 1, column_span: 1 }
+    }
+
+    /// Makes the cell cover `rows` rows and `columns` columns.
+    pub fn span(mut self, rows: usize, columns: usize) -> Self {
+        self.row_span = rows;
+        self.column_span = columns;
+        self
+    }
+}
+
+/// The cells of a grid, see [`LayoutEngine::grid`].
+pub struct GridCells<'items, ImageElementData: Debug, CustomElementData: Debug, CustomLayoutSettings> {
+    pub(crate) cells: Vec<(Cell, ContainerItem<'items, ImageElementData, CustomElementData, CustomLayoutSettings>)>,
+}
+
+impl<'items, ImageElementData: Debug, CustomElementData: Debug, CustomLayoutSettings> GridCells<'items, ImageElementData, CustomElementData, CustomLayoutSettings> {
+    pub(crate) fn new() -> Self {
+        Self { cells: Vec::new() }
+    }
+
+    /// Adds a text cell. Its size is measured with the engine's text renderer.
+    pub fn text(&mut self, cell: Cell, content: impl Into<Cow<'items, str>>, config: &TextConfig) -> &mut Self {
+        self.cells.push((cell, ContainerItem::Text { content: content.into(), config: *config }));
+        self
+    }
+
+    /// Adds an element cell. Its children are declared by `children`.
+    pub fn element<F>(&mut self, cell: Cell, config: &ElementConfiguration, children: F) -> &mut Self
+    where
+        F: FnOnce(&mut LayoutEngine<ImageElementData, CustomElementData, CustomLayoutSettings>) + 'items,
+    {
+        self.cells.push((cell, ContainerItem::Element { config: Box::new(*config), children: Box::new(children) }));
+        self
+    }
+}
+
+/// Sizes the tracks to fill `available`. `fit` holds the size of the largest single track cell of every track.
+pub(crate) fn size_tracks(tracks: &[Track], fit: &[f32], available: f32, gap: f32) -> Vec<f32> {
+    let mut sizes: Vec<f32> = tracks.iter().zip(fit).map(|(track, &fit)| match *track {
+        Track::Fixed(size) => size,
+        Track::Fit => fit,
+        Track::Grow(_) => 0.0,
+        Track::Percent(percent) => percent * available,
+    }).collect();
+
+    let gaps = gap * tracks.len().saturating_sub(1) as f32;
+    let left_over = (available - gaps - sizes.iter().sum::<f32>()).max(0.0);
+    let total_weight: f32 = tracks.iter().map(|track| match track {
+        Track::Grow(weight) => weight.max(0.0),
+        _ => 0.0,
+    }).sum();
+    if total_weight > 0.0 {
+        for (size, track) in sizes.iter_mut().zip(tracks) {
+            if let Track::Grow(weight) = track {
+                *size = left_over * weight.max(0.0) / total_weight;
+            }
+        }
+    }
+    sizes
+}
+
+/// The size of `span` tracks starting at `start`, including the gaps between them.
+pub(crate) fn span_size(sizes: &[f32], start: usize, span: usize, gap: f32) -> f32 {
+    sizes[start..start + span].iter().sum::<f32>() + gap * span.saturating_sub(1) as f32
+}
@@ -0,0 +1,128 @@
+use std::fmt::Debug;
+
+use crate::{LayoutEngine, MeasureText};
+
+/// The number of elements clay has room for unless told otherwise.
+pub const DEFAULT_MAX_ELEMENT_COUNT: u32 = 8192;
+/// The number of measured words clay caches unless told otherwise.
+pub const DEFAULT_MAX_MEASURE_TEXT_CACHE_WORD_COUNT: u32 = 16384;
+
+/// Settings used to create a [`LayoutEngine`].
+/// The arena of the engine is sized to fit the configured capacities.
+#[derive(Debug, Clone, Copy)]
+pub struct LayoutEngineBuilder {
+    /// The initial width and height of the layout.
+    pub dimensions: (f32, f32),
+    /// The maximum number of elements, including text elements, in one layout.
+    pub max_element_count: u32,
+    /// The maximum number of words kept in the text measurement cache.
+    pub max_measure_text_cache_word_count: u32,
+    /// Renders clay's debug view next to the layout.
+    pub debug_mode: bool,
+    /// Skips render commands for elements that are entirely off screen.
+    pub culling: bool,
+    /// Rebuilds the engine with larger capacities after a frame ran out of them.
+    pub auto_grow: bool,
+    /// The element capacity automatic growth never goes beyond.
+    pub max_element_count_limit: Option<u32>,
+    /// The text measurement cache capacity automatic growth never goes beyond.
+    pub max_measure_text_cache_word_count_limit: Option<u32>,
+    /// Mirrors the layout horizontally for right to left languages.
+    pub right_to_left: bool,
+}
+
+impl LayoutEngineBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the initial width and height of the layout.
+    pub fn dimensions(&mut self, width: f32, height: f32) -> &mut Self {
+        self.dimensions = (width, height);
+        self
+    }
+
+    /// Sets the maximum number of elements, including text elements, in one layout.
+    pub fn max_element_count(&mut self, count: u32) -> &mut Self {
+        self.max_element_count = count;
+        self
+    }
+
+    /// Sets the maximum number of words kept in the text measurement cache.
+    pub fn max_measure_text_cache_word_count(&mut self, count: u32) -> &mut Self {
+        self.max_measure_text_cache_word_count = count;
+        self
+    }
+
+    /// Enables or disables clay's debug view.
+    pub fn debug_mode(&mut self, enable: bool) -> &mut Self {
+        self.debug_mode = enable;
+        self
+    }
+
+    /// Enables or disables culling of off screen elements.
+    pub fn culling(&mut self, enable: bool) -> &mut Self {
+        self.culling = enable;
+        self
+    }
+
+    /// Enables or disables growing the capacities after a frame ran out of them.
+    pub fn auto_grow(&mut self, enable: bool) -> &mut Self {
+        self.auto_grow = enable;
+        self
+    }
+
+    /// Sets the element capacity automatic growth never goes beyond.
+    pub fn max_element_count_limit(&mut self, limit: u32) -> &mut Self {
+        self.max_element_count_limit = Some(limit);
+        self
+    }
+
+    /// Sets the text measurement cache capacity automatic growth never goes beyond.
+    pub fn max_measure_text_cache_word_count_limit(&mut self, limit: u32) -> &mut Self {
+        self.max_measure_text_cache_word_count_limit = Some(limit);
+        self
+    }
+
+    /// Enables or disables mirroring the layout for right to left languages.
+    pub fn right_to_left(&mut self, enable: bool) -> &mut Self {
+        self.right_to_left = enable;
+        self
+    }
+
+    /// Allocates an arena large enough for the configured capacities and creates the engine.
+    /// The engine measures its text with `text_renderer` for as long as it lives.
+    pub fn build<ImageElementData: Debug, CustomElementData: Debug, CustomLayoutSettings>(
+        &self,
+        text_renderer: impl MeasureText + 'static,
+    ) -> LayoutEngine<ImageElementData, CustomElementData, CustomLayoutSettings> {
+        assert!(self.max_element_count > 0, "max_element_count must be greater than 0!");
+        // Clay hashes words into max_measure_text_cache_word_count / 32 buckets, so fewer than 32 leaves it none.
+        assert!(
+            self.max_measure_text_cache_word_count >= 32,
+            "max_measure_text_cache_word_count must be at least 32!"
+        );
+        assert!(
+            self.max_element_count <= i32::MAX as u32 && self.max_measure_text_cache_word_count <= i32::MAX as u32,
+            "Capacities must fit in an i32!"
+        );
+
+        LayoutEngine::from_builder(self, Box::new(text_renderer))
+    }
+}
+
+impl Default for LayoutEngineBuilder {
+    fn default() -> Self {
+        Self {
+            dimensions: (0.0, 0.0),
+            max_element_count: DEFAULT_MAX_ELEMENT_COUNT,
+            max_measure_text_cache_word_count: DEFAULT_MAX_MEASURE_TEXT_CACHE_WORD_COUNT,
+            debug_mode: false,
+            culling: true,
+            auto_grow: true,
+            max_element_count_limit: None,
+            max_measure_text_cache_word_count_limit: None,
+            right_to_left: false,
+        }
+    }
+}
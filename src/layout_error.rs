@@ -0,0 +1,72 @@
+use std::fmt::Display;
+
+use crate::bindings::*;
+
+/// Errors reported by clay while a layout is being built.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LayoutError {
+    /// Text had to be measured, but no text measurement function was provided.
+    TextMeasurementFunctionNotProvided,
+    /// The arena the engine allocated for clay is too small.
+    ArenaCapacityExceeded,
+    /// More elements were declared than the engine has capacity for.
+    ElementsCapacityExceeded,
+    /// The text measurement cache ran out of space.
+    TextMeasurementCapacityExceeded,
+    /// Two elements were declared with the same id within one layout.
+    DuplicateId,
+    /// A floating element was attached to an id that does not exist in the layout.
+    FloatingContainerParentNotFound,
+    /// An element was sized with a percentage larger than 1.0.
+    PercentageOver1,
+    /// Clay encountered an internal error.
+    InternalError,
+}
+
+impl From<Clay_ErrorType> for LayoutError {
+    fn from(value: Clay_ErrorType) -> Self {
+        match value {
+            Clay_ErrorType::CLAY_ERROR_TYPE_TEXT_MEASUREMENT_FUNCTION_NOT_PROVIDED => LayoutError::TextMeasurementFunctionNotProvided,
+            Clay_ErrorType::CLAY_ERROR_TYPE_ARENA_CAPACITY_EXCEEDED => LayoutError::ArenaCapacityExceeded,
+            Clay_ErrorType::CLAY_ERROR_TYPE_ELEMENTS_CAPACITY_EXCEEDED => LayoutError::ElementsCapacityExceeded,
+            Clay_ErrorType::CLAY_ERROR_TYPE_TEXT_MEASUREMENT_CAPACITY_EXCEEDED => LayoutError::TextMeasurementCapacityExceeded,
+            Clay_ErrorType::CLAY_ERROR_TYPE_DUPLICATE_ID => LayoutError::DuplicateId,
+            Clay_ErrorType::CLAY_ERROR_TYPE_FLOATING_CONTAINER_PARENT_NOT_FOUND => LayoutError::FloatingContainerParentNotFound,
+            Clay_ErrorType::CLAY_ERROR_TYPE_PERCENTAGE_OVER_1 => LayoutError::PercentageOver1,
+            Clay_ErrorType::CLAY_ERROR_TYPE_INTERNAL_ERROR => LayoutError::InternalError,
+        }
+    }
+}
+
+impl Display for LayoutError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let message = match self {
+            LayoutError::TextMeasurementFunctionNotProvided => "text had to be measured, but no text measurement function was provided",
+            LayoutError::ArenaCapacityExceeded => "the layout arena is too small",
+            LayoutError::ElementsCapacityExceeded => "more elements were declared than the layout has capacity for",
+            LayoutError::TextMeasurementCapacityExceeded => "the text measurement cache ran out of space",
+            LayoutError::DuplicateId => "two elements were declared with the same id",
+            LayoutError::FloatingContainerParentNotFound => "a floating element was attached to an id that does not exist",
+            LayoutError::PercentageOver1 => "an element was sized with a percentage larger than 1.0",
+            LayoutError::InternalError => "clay encountered an internal error",
+        };
+        write!(f, "{message}")
+    }
+}
+
+impl std::error::Error for LayoutError {}
+
+/// The errors clay reported during the current frame.
+/// The engine keeps this boxed so clay can hold on to a pointer to it.
+#[derive(Debug, Default)]
+pub(crate) struct ErrorLog {
+    pub errors: Vec<LayoutError>,
+}
+
+/// Collects every error into the `ErrorLog` passed to clay as the error handler's user data.
+pub(crate) unsafe extern "C" fn error_handler(error_data: Clay_ErrorData) {
+    unsafe {
+        let log = &mut *(error_data.userData as *mut ErrorLog);
+        log.errors.push(error_data.errorType.into());
+    }
+}
@@ -13,60 +13,295 @@ use text_configuration::*;
 pub use text_configuration::TextConfig;
 pub use text_configuration::MeasureText;
 
+mod monospace;
+pub use monospace::MonospaceMeasurer;
+
+#[cfg(feature = "font_measure")]
+mod font_registry;
+#[cfg(feature = "font_measure")]
+pub use font_registry::{FontError, FontRegistry};
+
 mod element_configuration;
 pub use element_configuration::ElementConfiguration;
 
+mod sizing;
+pub use sizing::{Sizing, SizingError};
+
+mod style;
+pub use style::{Style, TextStyle};
+
+mod floating;
+pub use floating::{AttachPoint, AttachTo, Floating, FloatingClip, PointerCapture};
+
+mod element_id;
+pub use element_id::ElementId;
+
+mod pointer;
+use pointer::{hover_handler, HoverCallbacks};
+pub use pointer::{Interaction, InteractionEvent, PointerData, PointerState};
+
+mod layout_error;
+use layout_error::{error_handler, ErrorLog};
+pub use layout_error::LayoutError;
+
+mod text_arena;
+use text_arena::TextArena;
+
+mod shared;
+use shared::Shared;
+
+mod text_measurement;
+use text_measurement::MeasurementCache;
+pub use text_measurement::TextMeasurementStats;
+
+mod slab;
+use slab::Slab;
+pub use slab::{CustomElementHandle, ImageHandle, LayoutSettingsHandle};
+
+mod wrap;
+use wrap::{break_lines, ContainerItem, LineItem};
+pub use wrap::Wrap;
+
+mod grid;
+use grid::{size_tracks, span_size};
+pub use grid::{Cell, Grid, GridCells, Track};
+
+mod rich_text;
+use rich_text::words;
+pub use rich_text::Span;
+
+mod render_frame;
+pub use render_frame::RenderFrame;
+
+mod layout_engine_builder;
+pub use layout_engine_builder::{
+    LayoutEngineBuilder,
+    DEFAULT_MAX_ELEMENT_COUNT, DEFAULT_MAX_MEASURE_TEXT_CACHE_WORD_COUNT
+};
+
 use std::{
-    fmt::Debug, marker::PhantomData, os::raw::c_void,
+    borrow::Cow, collections::{HashMap, HashSet}, fmt::Debug, os::raw::c_void,
 };
 
-unsafe extern "C" fn error_handler(error_data: Clay_ErrorData) {
+/// Allocates an arena sized for the given capacities and initializes a clay context in it.
+fn create_context(
+    max_element_count: u32,
+    max_measure_text_cache_word_count: u32,
+    dimensions: (f32, f32),
+    errors: &Shared<ErrorLog>,
+    text_renderer: &Shared<MeasurementCache>,
+) -> (Vec<u8>, *mut Clay_Context) {
     unsafe {
-        let text = core::str::from_utf8_unchecked(
-            core::slice::from_raw_parts(
-            error_data.errorText.chars as *const u8,
-            error_data.errorText.length as _,
-            )
+        // Without a current context clay sizes new arenas and contexts from its global defaults.
+        let previous_context = Clay_GetCurrentContext();
+        Clay_SetCurrentContext(core::ptr::null_mut());
+        Clay_SetMaxElementCount(max_element_count as i32);
+        Clay_SetMaxMeasureTextCacheWordCount(max_measure_text_cache_word_count as i32);
+
+        let memory_size = Clay_MinMemorySize() as usize;
+        let memory = vec![0; memory_size];
+        let arena = Clay_CreateArenaWithCapacityAndMemory(memory_size, memory.as_ptr() as *mut c_void);
+
+        let context = Clay_Initialize(
+            arena,
+            Clay_Dimensions { width: dimensions.0, height: dimensions.1 },
+            Clay_ErrorHandler {
+                errorHandlerFunction: Some(error_handler),
+                userData: errors.user_data(),
+            },
+        );
+        // The new context is current until it is reset below.
+        Clay_SetMeasureTextFunction(
+            Some(measure_text_c_callback::<MeasurementCache>),
+            text_renderer.user_data(),
         );
 
-        println!("Clay Error: (type: {:?}) {:?}", error_data.errorType, text);
+        Clay_SetCurrentContext(core::ptr::null_mut());
+        Clay_SetMaxElementCount(DEFAULT_MAX_ELEMENT_COUNT as i32);
+        Clay_SetMaxMeasureTextCacheWordCount(DEFAULT_MAX_MEASURE_TEXT_CACHE_WORD_COUNT as i32);
+        Clay_SetCurrentContext(previous_context);
+
+        (memory, context)
+    }
+}
+
+/// The size of a fixed sizing axis.
+fn fixed_size(sizing: Clay_SizingAxis) -> Option<f32> {
+    match sizing.type_ {
+        Clay__SizingType::CLAY__SIZING_TYPE_FIXED => Some(unsafe { sizing.size.minMax.min }),
+        _ => None,
     }
 }
 
+/// Clay doesn't call the error handler when it runs out of room for elements,
+/// it replaces the whole layout with a single text command holding an error message instead.
+fn elements_capacity_exceeded(render_commands: &[Clay_RenderCommand]) -> bool {
+    const MESSAGE: &[u8] = b"Clay Error: Layout elements exceeded";
+
+    match render_commands {
+        [command] if command.commandType == Clay_RenderCommandType::CLAY_RENDER_COMMAND_TYPE_TEXT => {
+            let text = unsafe {
+                core::slice::from_raw_parts(
+                    command.renderData.text.stringContents.chars as *const u8,
+                    command.renderData.text.stringContents.length as usize,
+                )
+            };
+            text.starts_with(MESSAGE)
+        }
+        _ => false,
+    }
+}
 
 pub struct LayoutEngine<ImageElementData: Debug, CustomElementData: Debug, CustomLayoutSettings>{
     _memory: Vec<u8>,
     context: *mut Clay_Context,
-    _phantom: PhantomData<(CustomElementData, ImageElementData, CustomLayoutSettings)>,
     dangling_element_count: u32,
+    open_element_count: u32,
+    errors: Shared<ErrorLog>,
+    text_renderer: Shared<MeasurementCache>,
+    settings: LayoutEngineBuilder,
+    truncated: bool,
+    scroll_containers: HashSet<u32>,
+    scroll_containers_this_frame: HashSet<u32>,
+    clip_only_elements: HashSet<u32>,
+    clip_only_elements_this_frame: HashSet<u32>,
+    scroll_positions: HashMap<u32, Clay_Vector2>,
+    restored_scroll_positions: HashMap<u32, Clay_Vector2>,
+    text_arena: TextArena,
+    hover_callbacks: Shared<HoverCallbacks>,
+    pointer: PointerState,
+    hovered_elements: Vec<ElementId>,
+    pressed_elements: Vec<ElementId>,
+    interaction_events: Vec<InteractionEvent>,
+    interaction_events_stale: bool,
+    /// The root every element belongs to, the root container or a floating element, by element id.
+    element_roots: HashMap<u32, u32>,
+    element_roots_this_frame: HashMap<u32, u32>,
+    /// The floating elements open while declaring, with the number of elements open when they were configured.
+    open_floating_roots: Vec<(u32, u32)>,
+    image_dimensions: HashMap<u32, Vec2>,
+    /// The sizes of the containers the engine arranged itself in the last complete frame, by element id.
+    container_sizes: HashMap<u32, Vec2>,
+    containers_this_frame: Vec<u32>,
+    images: Slab<ImageElementData>,
+    custom_elements: Slab<CustomElementData>,
+    custom_layout_settings: Slab<CustomLayoutSettings>,
 }
 
 
 impl<ImageElementData: Debug, CustomElementData: Debug, CustomLayoutSettings> LayoutEngine<ImageElementData, CustomElementData, CustomLayoutSettings> {
-    pub fn new(dimensions: (f32,f32)) -> Self{
-        let memory_size = unsafe { Clay_MinMemorySize() as usize };
-        let memory = vec![0; memory_size];
-        let context;
+    /// Creates an engine that measures its text with `text_renderer` for as long as it lives.
+    pub fn new(dimensions: (f32,f32), text_renderer: impl MeasureText + 'static) -> Self{
+        LayoutEngineBuilder::new()
+            .dimensions(dimensions.0, dimensions.1)
+            .build(text_renderer)
+    }
 
-        unsafe {
-            let arena =
-                Clay_CreateArenaWithCapacityAndMemory(memory_size, memory.as_ptr() as *mut c_void);
-
-            context = Clay_Initialize(
-                arena,
-                Clay_Dimensions { width: dimensions.0, height: dimensions.1 },
-                Clay_ErrorHandler {
-                    errorHandlerFunction: Some(error_handler),
-                    userData: std::ptr::null_mut(),
-                },
-            );
-        }
+    pub(crate) fn from_builder(builder: &LayoutEngineBuilder, text_renderer: Box<dyn MeasureText>) -> Self {
+        let errors = Shared::<ErrorLog>::default();
+        let text_renderer = Shared::new(MeasurementCache::new(text_renderer));
+        let (memory, context) = create_context(
+            builder.max_element_count,
+            builder.max_measure_text_cache_word_count,
+            builder.dimensions,
+            &errors,
+            &text_renderer,
+        );
 
-        Self {
+        let mut engine = Self {
             _memory: memory,
             context,
-            _phantom: PhantomData{},
             dangling_element_count: 0,
+            open_element_count: 0,
+            errors,
+            text_renderer,
+            settings: *builder,
+            truncated: false,
+            scroll_containers: HashSet::new(),
+            scroll_containers_this_frame: HashSet::new(),
+            clip_only_elements: HashSet::new(),
+            clip_only_elements_this_frame: HashSet::new(),
+            scroll_positions: HashMap::new(),
+            restored_scroll_positions: HashMap::new(),
+            text_arena: TextArena::default(),
+            hover_callbacks: Shared::default(),
+            pointer: PointerState::Released,
+            hovered_elements: Vec::new(),
+            pressed_elements: Vec::new(),
+            interaction_events: Vec::new(),
+            interaction_events_stale: false,
+            element_roots: HashMap::new(),
+            element_roots_this_frame: HashMap::new(),
+            open_floating_roots: Vec::new(),
+            image_dimensions: HashMap::new(),
+            container_sizes: HashMap::new(),
+            containers_this_frame: Vec::new(),
+            images: Slab::default(),
+            custom_elements: Slab::default(),
+            custom_layout_settings: Slab::default(),
+        };
+
+        engine.set_debug_mode(builder.debug_mode);
+        engine.set_culling(builder.culling);
+        engine
+    }
+
+    /// Rebuilds the arena and context with doubled capacities for whatever the last frame ran out of.
+    /// Scroll positions from the last complete frame are carried over into the new context.
+    fn grow(&mut self) {
+        let mut settings = self.settings;
+        if self.errors.errors.contains(&LayoutError::ElementsCapacityExceeded) {
+            settings.max_element_count = settings.max_element_count
+                .saturating_mul(2)
+                .min(settings.max_element_count_limit.unwrap_or(i32::MAX as u32))
+                .max(self.settings.max_element_count);
+        }
+        if self.errors.errors.contains(&LayoutError::TextMeasurementCapacityExceeded) {
+            settings.max_measure_text_cache_word_count = settings.max_measure_text_cache_word_count
+                .saturating_mul(2)
+                .min(settings.max_measure_text_cache_word_count_limit.unwrap_or(i32::MAX as u32))
+                .max(self.settings.max_measure_text_cache_word_count);
+        }
+        if settings.max_element_count == self.settings.max_element_count
+            && settings.max_measure_text_cache_word_count == self.settings.max_measure_text_cache_word_count
+        {
+            return;
+        }
+
+        self.restored_scroll_positions = std::mem::take(&mut self.scroll_positions);
+
+        let old_context = self.context;
+        let (memory, context) = create_context(
+            settings.max_element_count,
+            settings.max_measure_text_cache_word_count,
+            settings.dimensions,
+            &self.errors,
+            &self.text_renderer,
+        );
+        unsafe {
+            if Clay_GetCurrentContext() == old_context {
+                Clay_SetCurrentContext(context);
+            }
+        }
+        self._memory = memory;
+        self.context = context;
+        self.settings = settings;
+
+        self.set_debug_mode(settings.debug_mode);
+        self.set_culling(settings.culling);
+    }
+
+    /// Returns true if the last frame ran out of element or text measurement capacity and was cut short.
+    /// Unless automatic growth is disabled, the next call to `begin_layout` rebuilds the engine with larger capacities.
+    pub fn truncated(&self) -> bool {
+        self.truncated
+    }
+
+    /// Makes this engine's context the one clay operates on.
+    /// Every method that calls into clay does this first, so several engines can be used side by side.
+    fn activate(&self) {
+        unsafe {
+            Clay_SetCurrentContext(self.context);
         }
     }
 
@@ -80,108 +315,655 @@ impl<ImageElementData: Debug, CustomElementData: Debug, CustomLayoutSettings> La
         }
     }
 
-    pub fn set_debug_mode(&self, enable: bool) {
+    pub fn set_debug_mode(&mut self, enable: bool) {
+        self.settings.debug_mode = enable;
+        self.activate();
         unsafe {
             Clay_SetDebugModeEnabled(enable);
         }
     }
 
-    pub fn set_layout_dimensions(&self, width: f32, height: f32) {
+    pub fn set_culling(&mut self, enable: bool) {
+        self.settings.culling = enable;
+        self.activate();
+        unsafe {
+            Clay_SetCullingEnabled(enable);
+        }
+    }
+
+    /// Replaces the text renderer the engine measures its text with.
+    /// Measurements made with the old renderer are dropped.
+    pub fn set_text_renderer(&mut self, text_renderer: impl MeasureText + 'static) {
+        self.text_renderer.set_renderer(Box::new(text_renderer));
+        self.reset_measure_text_cache();
+    }
+
+    /// Returns the text renderer the engine measures its text with, if it is a `T`.
+    /// Text already measured keeps its size, call `invalidate_font_measurements` or `invalidate_text_measurements`
+    /// after changing how the renderer measures it, like after replacing a font.
+    pub fn text_renderer_mut<T: MeasureText>(&mut self) -> Option<&mut T> {
+        self.text_renderer.renderer_mut()
+    }
+
+    /// Drops every cached text measurement, so all text is measured again.
+    /// Call this when fonts were replaced or the scale they are rendered at changed.
+    pub fn invalidate_text_measurements(&mut self) {
+        self.text_renderer.clear();
+        self.reset_measure_text_cache();
+    }
+
+    /// Drops the cached text measurements of one font, so text in it is measured again.
+    /// Clay's cache can only be dropped as a whole, text in other fonts is measured again from the engine's cache.
+    pub fn invalidate_font_measurements(&mut self, font_id: u16) {
+        self.text_renderer.remove_font(font_id);
+        self.reset_measure_text_cache();
+    }
+
+    fn reset_measure_text_cache(&self) {
+        self.activate();
+        unsafe {
+            Clay_ResetMeasureTextCache();
+        }
+    }
+
+    /// Returns how many text measurements the frame took so far, after `end_layout` those of the whole frame.
+    pub fn text_measurement_stats(&self) -> TextMeasurementStats {
+        self.text_renderer.stats
+    }
+
+    /// Returns the maximum number of elements, including text elements, in one layout.
+    pub fn max_element_count(&self) -> u32 {
+        self.activate();
+        unsafe { Clay_GetMaxElementCount() as u32 }
+    }
+
+    /// Returns the maximum number of words kept in the text measurement cache.
+    pub fn max_measure_text_cache_word_count(&self) -> u32 {
+        self.activate();
+        unsafe { Clay_GetMaxMeasureTextCacheWordCount() as u32 }
+    }
+
+    /// Enables or disables mirroring the layout for right to left languages.
+    /// Layouts are declared as usual, and their render commands, bounding boxes and pointer positions are mirrored.
+    pub fn set_right_to_left(&mut self, enable: bool) {
+        self.settings.right_to_left = enable;
+    }
+
+    pub fn right_to_left(&self) -> bool {
+        self.settings.right_to_left
+    }
+
+    pub fn set_layout_dimensions(&mut self, width: f32, height: f32) {
+        self.settings.dimensions = (width, height);
+        self.activate();
         unsafe {
             Clay_SetLayoutDimensions(Clay_Dimensions { width, height });
         }
     }
 
     pub fn begin_layout(&mut self){
+        if self.truncated {
+            if self.settings.auto_grow {
+                self.grow();
+            }
+        } else {
+            // A truncated frame loses clay's scroll data, so only complete frames are remembered.
+            self.scroll_positions = self.scroll_containers.iter().filter_map(|&id| {
+                self.scroll_container_data(id)
+                    .map(|data| (id, unsafe { *data.scrollPosition }))
+            }).collect();
+            // Clay resets the bounding box of a new element as soon as it is declared, so the sizes of the containers
+            // are taken before anything is.
+            self.container_sizes = self.containers_this_frame.iter().filter_map(|&id| {
+                self.bounding_box(id).map(|bounding_box| (id, Vec2 { x: bounding_box.width, y: bounding_box.height }))
+            }).collect();
+        }
+        self.containers_this_frame.clear();
+        self.truncated = false;
+        self.scroll_containers_this_frame.clear();
+        self.clip_only_elements_this_frame.clear();
+        self.element_roots_this_frame.clear();
+        self.open_floating_roots.clear();
+        self.image_dimensions.clear();
+
+        self.dangling_element_count = 0;
+        self.open_element_count = 0;
+        self.errors.errors.clear();
+        self.text_arena.reset();
+        self.text_renderer.stats = TextMeasurementStats::default();
+        if self.text_renderer.len() > self.settings.max_measure_text_cache_word_count as usize {
+            // Clay keeps what is in use, so the engine's copy only needs to cover it again from now on.
+            self.text_renderer.clear();
+        }
+        self.images.reset();
+        self.custom_elements.reset();
+        self.custom_layout_settings.reset();
+        self.hover_callbacks.callbacks.clear();
+        self.hover_callbacks.click_callbacks.clear();
+        self.activate();
         unsafe { 
             Clay_BeginLayout();
-            Clay_SetCurrentContext(self.context);
         };
     }
 
-    pub fn end_layout<'render_pass, TextRenderer: MeasureText>(&mut self, text_renderer: &mut TextRenderer) -> Vec<RenderCommand::<'render_pass, ImageElementData, CustomElementData, CustomLayoutSettings>> {
-        
-        let ptr: *mut TextRenderer = text_renderer;
-        let ptr = ptr as *mut c_void;
-        unsafe {
-            Clay_SetMeasureTextFunction(
-                Some(measure_text_c_callback::<TextRenderer>), 
-                ptr
-            );
-        }
-        
+    /// Finishes the layout and returns its render commands.
+    /// The returned frame borrows the engine until it is dropped.
+    pub fn end_layout(&mut self) -> RenderFrame<'_, ImageElementData, CustomElementData, CustomLayoutSettings> {
+        self.activate();
         assert!(
             self.dangling_element_count == 0 && self.dangling_element_count%2 == 0,
             "All elements must have a Configuration!"
         );
+        assert!(
+            self.open_element_count == 0,
+            "All elements must be closed before the layout ends!"
+        );
 
         let array = unsafe {
             let render_commands = Clay_EndLayout();
-            Clay_SetMeasureTextFunction(None, std::ptr::null::<c_void>() as _);
             core::slice::from_raw_parts(render_commands.internalArray, render_commands.length as usize)
         };
-        
-        array.iter().map(|command| {
+
+        if elements_capacity_exceeded(array) {
+            self.errors.errors.push(LayoutError::ElementsCapacityExceeded);
+        }
+
+        self.truncated = self.errors.errors.iter().any(|error| matches!(
+            error,
+            LayoutError::ElementsCapacityExceeded | LayoutError::TextMeasurementCapacityExceeded
+        ));
+        if !self.truncated {
+            std::mem::swap(&mut self.scroll_containers, &mut self.scroll_containers_this_frame);
+            std::mem::swap(&mut self.clip_only_elements, &mut self.clip_only_elements_this_frame);
+        }
+        std::mem::swap(&mut self.element_roots, &mut self.element_roots_this_frame);
+        self.interaction_events_stale = true;
+
+        // Nothing is changed past this point, so the commands can borrow the slabs for as long as the frame
+        // borrows the engine, which keeps the next layout from beginning while they are in use.
+        let engine: &Self = self;
+        let mut commands = array.iter().map(|command| {
+            let settings = engine.custom_layout_settings.get(command.userData);
             match command.commandType {
                 Clay_RenderCommandType::CLAY_RENDER_COMMAND_TYPE_NONE => RenderCommand::None,
-                Clay_RenderCommandType::CLAY_RENDER_COMMAND_TYPE_RECTANGLE => RenderCommand::Rectangle(command.into()),
-                Clay_RenderCommandType::CLAY_RENDER_COMMAND_TYPE_BORDER => RenderCommand::Border(command.into()),
-                Clay_RenderCommandType::CLAY_RENDER_COMMAND_TYPE_TEXT => RenderCommand::Text(command.into()),
-                Clay_RenderCommandType::CLAY_RENDER_COMMAND_TYPE_IMAGE => RenderCommand::Image(command.into()),
-                Clay_RenderCommandType::CLAY_RENDER_COMMAND_TYPE_CUSTOM => RenderCommand::Custom(command.into()),
+                Clay_RenderCommandType::CLAY_RENDER_COMMAND_TYPE_RECTANGLE => RenderCommand::Rectangle(Rectangle::new(command, settings)),
+                Clay_RenderCommandType::CLAY_RENDER_COMMAND_TYPE_BORDER => RenderCommand::Border(Border::new(command, settings)),
+                Clay_RenderCommandType::CLAY_RENDER_COMMAND_TYPE_TEXT => RenderCommand::Text(Text::new(command, settings)),
+                Clay_RenderCommandType::CLAY_RENDER_COMMAND_TYPE_IMAGE => {
+                    let data = engine.images.get(unsafe { command.renderData.image.imageData })
+                        .expect("Image commands always come from elements configured with a handle");
+                    let mut image = Image::new(command, data, settings);
+                    if let Some(&dimensions) = engine.image_dimensions.get(&command.id) {
+                        image.dimensions = dimensions;
+                    }
+                    RenderCommand::Image(image)
+                }
+                Clay_RenderCommandType::CLAY_RENDER_COMMAND_TYPE_CUSTOM => {
+                    let data = engine.custom_elements.get(unsafe { command.renderData.custom.customData })
+                        .expect("Custom commands always come from elements configured with a handle");
+                    RenderCommand::Custom(Custom::new(command, data, settings))
+                }
                 Clay_RenderCommandType::CLAY_RENDER_COMMAND_TYPE_SCISSOR_START => RenderCommand::ScissorStart(command.into()),
                 Clay_RenderCommandType::CLAY_RENDER_COMMAND_TYPE_SCISSOR_END => RenderCommand::ScissorEnd
             }
-        }).collect::<Vec<RenderCommand::<ImageElementData, CustomElementData, CustomLayoutSettings>>>()
+        }).collect::<Vec<RenderCommand::<ImageElementData, CustomElementData, CustomLayoutSettings>>>();
+
+        if engine.settings.right_to_left {
+            for command in &mut commands {
+                command.mirror(engine.settings.dimensions.0);
+            }
+        }
+
+        RenderFrame::new(engine, commands)
     }
 
-    pub fn open_element(&mut self){
+    /// Returns the errors clay reported since the last call to `begin_layout`.
+    pub fn errors(&self) -> &[LayoutError] {
+        &self.errors.errors
+    }
+
+    pub(crate) fn open_element(&mut self){
         self.dangle();
+        self.open_element_count += 1;
+        self.activate();
         unsafe {
             Clay__OpenElement();
         }
     }
 
-    pub fn close_element(&mut self){
+    pub(crate) fn close_element(&mut self){
         assert!(
             self.dangling_element_count == 0 && self.dangling_element_count%2 == 0,
             "All elements must have a Configuration!"
         );
+        assert!(
+            self.open_element_count > 0,
+            "close_element was called without a matching open_element!"
+        );
+        if self.open_floating_roots.last().is_some_and(|&(depth, _)| depth == self.open_element_count) {
+            self.open_floating_roots.pop();
+        }
+        self.open_element_count -= 1;
 
+        self.activate();
         unsafe {
             Clay__CloseElement();
         }
     }
 
-    pub fn configure_element<'render_pass>(&mut self, config: &ElementConfiguration) -> u32 {
+    /// Opens an element, configures it, declares its children and closes it again.
+    ///
+    /// Elements are only declared this way, so they are always configured and always balanced.
+    /// Returns the id of the element.
+    ///
+    /// ```compile_fail
+    /// use telera_layout::{ElementConfiguration, LayoutEngine, MonospaceMeasurer};
+    ///
+    /// let mut layout = LayoutEngine::<(), (), ()>::new((80.0, 24.0), MonospaceMeasurer::default());
+    /// layout.begin_layout();
+    /// layout.element(&ElementConfiguration::new(), |layout| {
+    ///     layout.open_element();
+    /// });
+    /// ```
+    pub fn element<F: FnOnce(&mut Self)>(&mut self, config: &ElementConfiguration, children: F) -> ElementId {
+        self.open_element();
+        let id = self.configure_element(config);
+        let depth = self.open_element_count;
+
+        children(self);
+
+        assert!(
+            self.open_element_count == depth,
+            "All elements opened inside an element scope must be closed before the scope ends!"
+        );
+        self.close_element();
+        id
+    }
+
+    /// Stores image data until the next layout begins and returns a handle for `ElementConfiguration::image`.
+    pub fn store_image(&mut self, image: ImageElementData) -> ImageHandle {
+        ImageHandle(self.images.insert(image))
+    }
+
+    /// Stores custom element data until the next layout begins and returns a handle for `ElementConfiguration::custom_element`.
+    pub fn store_custom_element(&mut self, custom_element: CustomElementData) -> CustomElementHandle {
+        CustomElementHandle(self.custom_elements.insert(custom_element))
+    }
+
+    /// Stores custom layout settings until the next layout begins and returns a handle for `ElementConfiguration::custom_layout_settings`.
+    pub fn store_layout_settings(&mut self, settings: CustomLayoutSettings) -> LayoutSettingsHandle {
+        LayoutSettingsHandle(self.custom_layout_settings.insert(settings))
+    }
+
+    pub(crate) fn configure_element<'render_pass>(&mut self, config: &ElementConfiguration) -> ElementId {
+        if let Some(error) = config.sizing_error() {
+            panic!("Invalid element sizing: {error}");
+        }
         self.undangle();
-        unsafe {
-            Clay__ConfigureOpenElement(config.into());
+        self.activate();
+        let mut declaration: Clay_ElementDeclaration = config.into();
+        if let Some(image) = config.get_image() {
+            declaration.image.imageData = self.images.user_data(image.0);
+        }
+        if let Some(custom_element) = config.get_custom_element() {
+            declaration.custom.customData = self.custom_elements.user_data(custom_element.0);
+        }
+        if let Some(settings) = config.get_custom_layout_settings() {
+            declaration.userData = self.custom_layout_settings.user_data(settings.0);
+        }
+        if config.is_managed_scroll_container() {
+            declaration.clip.childOffset = self.managed_scroll_offset(declaration.id.id);
+        }
+        let had_scroll_data = declaration.id.id != 0 && self.scroll_container_data(declaration.id.id).is_some();
+        let id = unsafe {
+            Clay__ConfigureOpenElement(declaration);
             Clay_GetOpenElementId()
+        };
+
+        if let Some(dimensions) = config.get_image_dimensions() {
+            self.image_dimensions.insert(id, dimensions);
+        }
+
+        if declaration.floating.attachTo != Clay_FloatingAttachToElement::CLAY_ATTACH_TO_NONE {
+            self.open_floating_roots.push((self.open_element_count, id));
+        }
+        let root = match self.open_floating_roots.last() {
+            Some(&(_, root)) => root,
+            None => ElementId::new("Clay__RootContainer").id,
+        };
+        self.element_roots_this_frame.insert(id, root);
+
+        if declaration.clip.horizontal || declaration.clip.vertical {
+            self.scroll_containers_this_frame.insert(id);
+            if config.is_clip_only() {
+                self.clip_only_elements_this_frame.insert(id);
+            }
+
+            // Scroll data created by this frame starts at the position the engine remembered.
+            let position = self.restored_scroll_positions.remove(&id).or(self.scroll_positions.get(&id).copied());
+            if !had_scroll_data
+                && let Some(position) = position
+                && let Some(data) = self.scroll_container_data(id)
+            {
+                unsafe {
+                    *data.scrollPosition = position;
+                }
+            }
+        }
+
+        // Elements declared without an id get one generated by clay.
+        if declaration.id.id == id {
+            declaration.id.into()
+        } else {
+            id.into()
         }
     }
     
-    pub fn add_text_element<'render_pass, TextRenderer: MeasureText>(&mut self, content: &'render_pass str, config: &'render_pass TextConfig, statically_allicated: bool, text_renderer: &mut TextRenderer) {
-        
-        let ptr: *mut TextRenderer = text_renderer;
-        let ptr = ptr as *mut c_void;
-        unsafe {
-            Clay_SetMeasureTextFunction(
-                Some(measure_text_c_callback::<TextRenderer>), 
-                ptr
+    /// Adds a container that breaks its items into as many lines as it takes to fit them.
+    /// Lines run along the direction of `config` and are stacked across it, `child_gap` separates both the
+    /// items and the lines, and the alignment along the lines applies to every line.
+    /// It clips items too long for it along its lines rather than growing to fit them.
+    ///
+    /// Clipping shows up as `ScissorStart` and `ScissorEnd` commands. A container that is not of a fixed size along
+    /// its lines always clips, so it can shrink again. Its lines only clip while they are too long or hold element
+    /// items, which might have grown since the previous frame.
+    ///
+    /// Text is measured right away, but the container and its element items are arranged by the size they had
+    /// in the previous frame unless they are of a fixed size. Those without an id are told apart by their position,
+    /// so they settle one frame after they first appear, or after the layout around them changes.
+    pub fn wrap<'items, F>(&mut self, config: &ElementConfiguration, items: F) -> ElementId
+    where
+        F: FnOnce(&mut Wrap<'items, ImageElementData, CustomElementData, CustomLayoutSettings>),
+    {
+        let mut wrap = Wrap::new();
+        items(&mut wrap);
+
+        let layout = config.get_layout();
+        let horizontal = layout.layoutDirection == Clay_LayoutDirection::CLAY_LEFT_TO_RIGHT;
+        let main_axis = |size: Vec2| if horizontal { size.x } else { size.y };
+
+        let mut container = *config;
+        container.set_layout(Clay_LayoutConfig {
+            layoutDirection: if horizontal { Clay_LayoutDirection::CLAY_TOP_TO_BOTTOM } else { Clay_LayoutDirection::CLAY_LEFT_TO_RIGHT },
+            ..layout
+        });
+        let mut line = ElementConfiguration::new();
+        line.set_layout(Clay_LayoutConfig {
+            sizing: if horizontal {
+                Clay_Sizing { width: Sizing::grow().into(), height: Sizing::fit().into() }
+            } else {
+                Clay_Sizing { width: Sizing::fit().into(), height: Sizing::grow().into() }
+            },
+            padding: Clay_Padding::default(),
+            ..layout
+        });
+        // Clay squeezes the items of a line too long for it down to their smallest size, which would then be taken
+        // for their size in the next frame. A clipping line leaves them at their own size.
+        let mut overflowing_line = line;
+        overflowing_line.clip(!horizontal, horizontal);
+
+        let clip = container.get_clip();
+        let fixed_size = |sizing: Clay_SizingAxis| sizing.type_ == Clay__SizingType::CLAY__SIZING_TYPE_FIXED;
+        let main_sizing = if horizontal { layout.sizing.width } else { layout.sizing.height };
+        if !fixed_size(main_sizing) && !clip.horizontal && !clip.vertical {
+            // Clay keeps a container at least as large as the lines inside it, which would keep it from ever
+            // shrinking below the lines it was broken into before. Clipping ignores their size.
+            container.clip(!horizontal, horizontal);
+        }
+
+        let gap = layout.childGap as f32;
+        let mut items = wrap.items;
+        self.element(&container, |layout| {
+            let id = layout.open_element_id();
+            let available = main_axis(layout.inner_size(config, id));
+            for (index, item) in items.iter_mut().enumerate() {
+                item.identify(id, index as u32);
+            }
+
+            let line_items: Vec<LineItem> = items.iter().map(|item| LineItem {
+                size: main_axis(layout.item_size(item)),
+                trailing_whitespace: if horizontal { layout.trailing_whitespace(item) } else { 0.0 },
+                line_break: matches!(item, ContainerItem::LineBreak { .. }),
+            }).collect();
+            let lines = break_lines(&line_items, available, gap);
+            // Items sized by the previous frame may have grown since.
+            let estimated: Vec<bool> = items.iter().map(|item| match item {
+                ContainerItem::Element { config, .. } => {
+                    let sizing = config.get_layout().sizing;
+                    !fixed_size(if horizontal { sizing.width } else { sizing.height })
+                }
+                _ => false,
+            }).collect();
+
+            let mut items = items.into_iter();
+            for range in lines {
+                let length = line_items[range.clone()].iter().map(|item| item.size).sum::<f32>()
+                    + gap * range.len().saturating_sub(1) as f32;
+                let overflows = length > available || estimated[range.clone()].contains(&true);
+                layout.element(if overflows { &overflowing_line } else { &line }, |layout| {
+                    for item in items.by_ref().take(range.len()) {
+                        item.declare(layout);
+                    }
+                });
+            }
+        })
+    }
+
+    /// Adds a grid whose cells line up across rows and columns.
+    /// The tracks are sized once for the whole grid, cells fill the tracks they cover and are aligned within them
+    /// by the child alignment of `config`. Positions without a cell are left empty.
+    /// Along an axis the grid fits its content on, `Grow` and `Percent` tracks fit their cells.
+    /// Like the items of [`LayoutEngine::wrap`], the grid and its element cells are arranged by their size in the
+    /// previous frame unless they are of a fixed size.
+    /// Panics if a track is invalid, see [`Track::validate`].
+    pub fn grid<'items, F>(&mut self, config: &ElementConfiguration, grid: &Grid, cells: F) -> ElementId
+    where
+        F: FnOnce(&mut GridCells<'items, ImageElementData, CustomElementData, CustomLayoutSettings>),
+    {
+        let mut grid_cells = GridCells::new();
+        cells(&mut grid_cells);
+
+        let (rows, columns) = (grid.rows.len(), grid.columns.len());
+        let mut occupants = vec![None; rows * columns];
+        for (index, (cell, _)) in grid_cells.cells.iter().enumerate() {
+            assert!(
+                cell.row_span > 0 && cell.column_span > 0 && cell.row + cell.row_span <= rows && cell.column + cell.column_span <= columns,
+                "Grid cells must lie within the rows and columns of the grid!"
             );
+            for row in cell.row..cell.row + cell.row_span {
+                for column in cell.column..cell.column + cell.column_span {
+                    assert!(occupants[row * columns + column].is_none(), "Grid cells can not overlap!");
+                    occupants[row * columns + column] = Some(index);
+                }
+            }
+        }
+
+        for track in grid.columns.iter().chain(&grid.rows) {
+            if let Err(error) = track.validate() {
+                panic!("Invalid grid track: {error}");
+            }
+        }
+
+        // A grid fitting its content would only ever grow into the size its tracks took up before,
+        // so along such an axis the tracks that share out the grid's size fit their cells instead.
+        let layout = config.get_layout();
+        let grows = |sizing: Clay_SizingAxis| sizing.type_ == Clay__SizingType::CLAY__SIZING_TYPE_GROW;
+        let fit_content = |tracks: &[Track], sizing: Clay_SizingAxis| -> Vec<Track> {
+            tracks.iter().map(|&track| match track {
+                Track::Grow(_) | Track::Percent(_) if sizing.type_ == Clay__SizingType::CLAY__SIZING_TYPE_FIT => Track::Fit,
+                track => track,
+            }).collect()
+        };
+        let column_tracks = fit_content(&grid.columns, layout.sizing.width);
+        let row_tracks = fit_content(&grid.rows, layout.sizing.height);
+
+        let mut container = *config;
+        container.set_layout(Clay_LayoutConfig {
+            childGap: grid.row_gap,
+            layoutDirection: Clay_LayoutDirection::CLAY_TOP_TO_BOTTOM,
+            ..layout
+        });
+        let clip = container.get_clip();
+        if !clip.horizontal && !clip.vertical && (grows(layout.sizing.width) || grows(layout.sizing.height)) {
+            // Growing grids would otherwise be held at least as large as the tracks they had before, like wrap containers.
+            container.clip(grows(layout.sizing.height), grows(layout.sizing.width));
         }
-        
+        let fixed = |width: f32, height: f32, child_gap: u16| {
+            let mut config = ElementConfiguration::new();
+            config.set_layout(Clay_LayoutConfig {
+                sizing: Clay_Sizing { width: Sizing::Fixed(width).into(), height: Sizing::Fixed(height).into() },
+                padding: Clay_Padding::default(),
+                childGap: child_gap,
+                childAlignment: layout.childAlignment,
+                layoutDirection: Clay_LayoutDirection::CLAY_LEFT_TO_RIGHT,
+            });
+            config
+        };
+        // Cells in Fit tracks are sized by the previous frame and may have grown since. Clay would squeeze them
+        // into their old size, which would then be taken for their size in the next frame, so they are clipped instead.
+        let estimated = |item: &ContainerItem<'_, _, _, _>, fit: bool, width: bool| match item {
+            ContainerItem::Element { config, .. } if fit => {
+                let sizing = config.get_layout().sizing;
+                let sizing = if width { sizing.width } else { sizing.height };
+                sizing.type_ != Clay__SizingType::CLAY__SIZING_TYPE_FIXED
+            }
+            _ => false,
+        };
+
+        let (placements, mut items): (Vec<Cell>, Vec<_>) = grid_cells.cells.into_iter().unzip();
+        let (column_gap, row_gap) = (grid.column_gap as f32, grid.row_gap as f32);
+        self.element(&container, |layout| {
+            let id = layout.open_element_id();
+            for (index, item) in items.iter_mut().enumerate() {
+                item.identify(id, index as u32);
+            }
+
+            let mut fit_columns = vec![0.0f32; columns];
+            let mut fit_rows = vec![0.0f32; rows];
+            for (cell, item) in placements.iter().zip(&items) {
+                let size = layout.item_size(item);
+                if cell.column_span == 1 {
+                    fit_columns[cell.column] = fit_columns[cell.column].max(size.x);
+                }
+                if cell.row_span == 1 {
+                    fit_rows[cell.row] = fit_rows[cell.row].max(size.y);
+                }
+            }
+            let available = layout.inner_size(config, id);
+            let widths = size_tracks(&column_tracks, &fit_columns, available.x, column_gap);
+            let heights = size_tracks(&row_tracks, &fit_rows, available.y, row_gap);
+
+            let mut items: Vec<_> = items.into_iter().map(Some).collect();
+            for row in 0..rows {
+                let width = span_size(&widths, 0, columns, column_gap);
+                layout.element(&fixed(width, heights[row], grid.column_gap), |layout| {
+                    let mut column = 0;
+                    while column < columns {
+                        let Some(index) = occupants[row * columns + column] else {
+                            layout.element(&fixed(widths[column], 0.0, 0), |_| {});
+                            column += 1;
+                            continue;
+                        };
+                        let cell = placements[index];
+                        let width = span_size(&widths, cell.column, cell.column_span, column_gap);
+                        if cell.row == row {
+                            // Cells spanning rows overflow their first row, the rows below keep their space free.
+                            let height = span_size(&heights, row, cell.row_span, row_gap);
+                            let item = items[index].take().unwrap();
+                            let mut wrapper = fixed(width, height, 0);
+                            let clip_width = estimated(&item, cell.column_span == 1 && column_tracks[cell.column] == Track::Fit, true);
+                            let clip_height = estimated(&item, cell.row_span == 1 && row_tracks[cell.row] == Track::Fit, false);
+                            if clip_width || clip_height {
+                                wrapper.clip(clip_height, clip_width);
+                            }
+                            layout.element(&wrapper, |layout| item.declare(layout));
+                        } else {
+                            layout.element(&fixed(width, 0.0, 0), |_| {});
+                        }
+                        column += cell.column_span;
+                    }
+                });
+            }
+        })
+    }
+
+    /// The fixed width and height of an element, or else its size in the previous frame.
+    fn known_size(&self, config: &ElementConfiguration) -> (Option<f32>, Option<f32>) {
+        let previous = match config.get_id() {
+            0 => None,
+            id => self.bounding_box(id),
+        };
+        let sizing = config.get_layout().sizing;
+        (
+            fixed_size(sizing.width).or(previous.map(|bounding_box| bounding_box.width)),
+            fixed_size(sizing.height).or(previous.map(|bounding_box| bounding_box.height)),
+        )
+    }
+
+    /// The space inside the padding of the open container `id` the engine arranges itself, by its fixed size
+    /// or its size in the previous frame. Sizes that are not known yet fall back to the size of the layout.
+    fn inner_size(&mut self, config: &ElementConfiguration, id: u32) -> Vec2 {
+        self.containers_this_frame.push(id);
+        let previous = self.container_sizes.get(&id);
+        let sizing = config.get_layout().sizing;
+        let padding = config.get_layout().padding;
+        Vec2 {
+            x: fixed_size(sizing.width).or(previous.map(|size| size.x)).unwrap_or(self.settings.dimensions.0)
+                - (padding.left + padding.right) as f32,
+            y: fixed_size(sizing.height).or(previous.map(|size| size.y)).unwrap_or(self.settings.dimensions.1)
+                - (padding.top + padding.bottom) as f32,
+        }
+    }
+
+    /// The size of an item of a container the engine arranges itself. Sizes that are not known yet are 0.
+    fn item_size(&mut self, item: &ContainerItem<'_, ImageElementData, CustomElementData, CustomLayoutSettings>) -> Vec2 {
+        match item {
+            ContainerItem::Text { content, config } => self.text_renderer.measure_uncached(content, *config),
+            ContainerItem::TextRun { parts } => parts.iter().fold(Vec2::default(), |size, (content, config)| {
+                let part = self.text_renderer.measure_uncached(content, *config);
+                Vec2 { x: size.x + part.x, y: size.y.max(part.y) }
+            }),
+            ContainerItem::Element { config, .. } => {
+                let (width, height) = self.known_size(config);
+                Vec2 { x: width.unwrap_or_default(), y: height.unwrap_or_default() }
+            }
+            ContainerItem::LineBreak { config } => Vec2 { x: 0.0, y: self.text_renderer.measure_uncached(" ", *config).y },
+        }
+    }
+
+    /// The width of the whitespace a text item ends with.
+    fn trailing_whitespace(&mut self, item: &ContainerItem<'_, ImageElementData, CustomElementData, CustomLayoutSettings>) -> f32 {
+        let (content, config) = match item {
+            ContainerItem::Text { content, config } => (content, config),
+            ContainerItem::TextRun { parts } => match parts.last() {
+                Some((content, config)) => (content, config),
+                None => return 0.0,
+            },
+            _ => return 0.0,
+        };
+        let trimmed = content.trim_end();
+        if trimmed.len() == content.len() {
+            return 0.0;
+        }
+        self.text_renderer.measure_uncached(content, *config).x - self.text_renderer.measure_uncached(trimmed, *config).x
+    }
+
+    /// Adds a text element to the currently open element.
+    /// The text is kept by the engine until the next layout begins, so formatted strings can be passed directly.
+    pub fn add_text_element<'a>(&mut self, content: impl Into<Cow<'a, str>>, config: &TextConfig) {
+        self.activate();
         assert!(
             self.dangling_element_count == 0 && self.dangling_element_count%2 == 0,
             "All elements must have a Configuration!"
         );
 
+        let content = self.text_arena.store(content.into());
         let text_config = unsafe { Clay__StoreTextElementConfig(config.into()) };
+        let lookups = self.text_renderer.lookups;
         unsafe { 
             Clay__OpenTextElement( 
                 Clay_String { 
-                    isStaticallyAllocated: statically_allicated, 
+                    isStaticallyAllocated: false, 
                     length: content.len() as i32, 
                     chars: content.as_ptr() as *mut _
                 }, 
@@ -189,53 +971,232 @@ impl<ImageElementData: Debug, CustomElementData: Debug, CustomLayoutSettings> La
             ) 
         };
 
-        unsafe {
-            Clay_SetMeasureTextFunction(None, std::ptr::null::<c_void>() as _);
+        // Clay only calls back into the engine for text it has not cached.
+        if self.text_renderer.lookups == lookups {
+            self.text_renderer.stats.cache_hits += 1;
+        } else {
+            self.text_renderer.stats.cache_misses += 1;
         }
     }
 
-    pub fn pointer_state(&self, x: f32, y: f32, is_down: bool) {
+    /// Adds a paragraph of differently styled spans to the currently open element.
+    /// The paragraph breaks into lines between words, also across spans, and at every new line.
+    /// Every word keeps the style of its span, and whitespace after the last word of a line may hang past its end.
+    /// Lines are aligned by the child alignment of `config`, while its direction and child gap are ignored.
+    /// A paragraph that is not of a fixed width is broken into lines at its width in the previous frame and clips its
+    /// lines, so it is rendered between scissor commands, see [`LayoutEngine::wrap`].
+    pub fn add_rich_text(&mut self, config: &ElementConfiguration, spans: &[Span]) -> ElementId {
+        let mut paragraph = *config;
+        paragraph.set_layout(Clay_LayoutConfig {
+            childGap: 0,
+            layoutDirection: Clay_LayoutDirection::CLAY_LEFT_TO_RIGHT,
+            ..config.get_layout()
+        });
+        self.wrap(&paragraph, |wrap| wrap.items = words(spans))
+    }
+
+    /// Adds a text element to the currently open element.
+    pub fn text<'a>(&mut self, content: impl Into<Cow<'a, str>>, config: &TextConfig) {
+        self.add_text_element(content, config);
+    }
+
+    /// Updates the pointer and calls the hover callbacks of the elements under it in the last layout.
+    /// Interaction events are collected from every call since the last layout ended.
+    pub fn pointer_state(&mut self, x: f32, y: f32, is_down: bool) {
+        self.pointer = self.pointer.next(is_down);
+        self.hover_callbacks.state = self.pointer;
+        self.hover_callbacks.position = Vec2 { x, y };
+        // Clay lays out right to left layouts as left to right ones, so it is given the mirrored position.
+        let x = if self.settings.right_to_left { self.settings.dimensions.0 - x } else { x };
+        self.activate();
         unsafe {
             Clay_SetPointerState(Clay_Vector2 { x, y }, is_down);
         }
+        let hovered = self.pointer_over_ids();
+
+        if self.interaction_events_stale {
+            self.interaction_events.clear();
+            self.interaction_events_stale = false;
+        }
+        self.record_interactions(hovered);
+    }
+
+    /// Returns every element under the pointer, from the topmost to the bottom one.
+    /// Elements hidden by a clipping parent are left out, and so is everything below a floating element that captures the pointer.
+    pub fn pointer_over_ids(&self) -> Vec<ElementId> {
+        self.activate();
+        let ids = unsafe {
+            let ids = Clay_GetPointerOverIds();
+            if ids.length == 0 {
+                return Vec::new();
+            }
+            core::slice::from_raw_parts(ids.internalArray, ids.length as usize)
+        };
+
+        // Clay goes through the layout one root at a time, topmost root first,
+        // but visits the elements of a root from the bottom up, parents before their children.
+        // A root itself is not always under the pointer when its children stick out of it, so the ids are grouped
+        // by the root recorded for them. Ids the engine never configured, like those of text, stay with the ones before.
+        let root_container = ElementId::new("Clay__RootContainer").id;
+        let mut ordered: Vec<ElementId> = Vec::with_capacity(ids.len());
+        let mut root_start = 0;
+        let mut current_root = None;
+        for &id in ids {
+            let root = match id.id {
+                id if id == root_container => Some(root_container),
+                id => self.element_roots.get(&id).copied().or(current_root),
+            };
+            if root != current_root {
+                ordered[root_start..].reverse();
+                root_start = ordered.len();
+                current_root = root;
+            }
+            ordered.push(id.into());
+        }
+        ordered[root_start..].reverse();
+        ordered
+    }
+
+    fn record_interactions(&mut self, hovered: Vec<ElementId>) {
+        let mut push = |id: ElementId, interaction: Interaction| {
+            self.interaction_events.push(InteractionEvent { id, interaction });
+        };
+
+        for &id in self.hovered_elements.iter().filter(|id| !hovered.contains(id)) {
+            push(id, Interaction::HoverLeave);
+        }
+        for &id in hovered.iter().filter(|id| !self.hovered_elements.contains(id)) {
+            push(id, Interaction::HoverEnter);
+        }
+
+        match self.pointer {
+            PointerState::PressedThisFrame => {
+                for &id in &hovered {
+                    push(id, Interaction::Press);
+                }
+                self.pressed_elements = hovered.clone();
+            }
+            PointerState::ReleasedThisFrame => {
+                for &id in &hovered {
+                    push(id, Interaction::Release);
+                }
+                let pointer_data = PointerData { position: self.hover_callbacks.position, state: self.pointer };
+                for &id in hovered.iter().filter(|id| self.pressed_elements.contains(id)) {
+                    push(id, Interaction::Click);
+                    if let Some(callbacks) = self.hover_callbacks.click_callbacks.get_mut(&id.id) {
+                        for callback in callbacks {
+                            callback(id, pointer_data);
+                        }
+                    }
+                }
+                self.pressed_elements.clear();
+            }
+            _ => {}
+        }
+
+        self.hovered_elements = hovered;
+    }
+
+    /// Returns what the pointer did to elements since the last layout ended.
+    pub fn interaction_events(&self) -> &[InteractionEvent] {
+        &self.interaction_events
+    }
+
+    /// Returns true if the element saw the given interaction since the last layout ended.
+    pub fn interacted(&self, id: impl Into<ElementId>, interaction: Interaction) -> bool {
+        let id = id.into();
+        self.interaction_events.iter().any(|event| event.id == id && event.interaction == interaction)
+    }
+
+    pub fn hover_entered(&self, id: impl Into<ElementId>) -> bool {
+        self.interacted(id, Interaction::HoverEnter)
+    }
+
+    pub fn hover_left(&self, id: impl Into<ElementId>) -> bool {
+        self.interacted(id, Interaction::HoverLeave)
+    }
+
+    pub fn pressed(&self, id: impl Into<ElementId>) -> bool {
+        self.interacted(id, Interaction::Press)
     }
 
+    pub fn released(&self, id: impl Into<ElementId>) -> bool {
+        self.interacted(id, Interaction::Release)
+    }
+
+    pub fn clicked(&self, id: impl Into<ElementId>) -> bool {
+        self.interacted(id, Interaction::Click)
+    }
+
+    /// Scrolls the innermost scroll container under the pointer by the wheel delta, or drags it along with the
+    /// pointer while the button is down. Dragged containers keep moving for a while after they are let go.
+    /// Elements that only clip never keep the container around them from scrolling, and nothing is scrolled
+    /// after a truncated frame.
     pub fn update_scroll_containers(
-        &self,
+        &mut self,
         drag_scrolling_enabled: bool,
         delta_x: f32,
         delta_y: f32,
         delta_time: f32,
     ) {
+        // Clay's scroll data still points into the elements of a truncated frame, which are not all there.
+        if self.truncated {
+            return;
+        }
+        self.activate();
+        let delta_x = if self.settings.right_to_left { -delta_x } else { delta_x };
+        // Clay scrolls the innermost clipping element under the pointer, so elements that only clip are hidden from it
+        // while it scrolls and the container around them is scrolled instead.
+        let ids = unsafe { Clay_GetPointerOverIds() };
+        let ids = match ids.length {
+            0 => &mut [][..],
+            length => unsafe { core::slice::from_raw_parts_mut(ids.internalArray, length as usize) },
+        };
+        let hidden: Vec<(usize, Clay_ElementId)> = ids.iter().enumerate()
+            .filter(|(_, id)| self.clip_only_elements.contains(&id.id))
+            .map(|(index, &id)| (index, id))
+            .collect();
+        for &(index, _) in &hidden {
+            ids[index].id = 0;
+        }
         unsafe {
             Clay_UpdateScrollContainers(drag_scrolling_enabled, Clay_Vector2 { x: delta_x, y: delta_y }, delta_time);
         }
+        for (index, id) in hidden {
+            ids[index] = id;
+        }
+    }
+
+    fn managed_scroll_offset(&self, id: u32) -> Clay_Vector2 {
+        if id == 0 {
+            // Anonymous elements only get their id while being configured, so clay looks them up by position.
+            return self.get_scroll_offset();
+        }
+        if let Some(data) = self.scroll_container_data(id) {
+            return unsafe { *data.scrollPosition };
+        }
+        // After growing or a truncated frame, clay's scroll data is gone and the position is only known to the engine.
+        self.restored_scroll_positions.get(&id)
+            .or(self.scroll_positions.get(&id))
+            .copied()
+            .unwrap_or_default()
     }
 
     pub fn get_scroll_offset(&self) -> Clay_Vector2{
+        self.activate();
         unsafe {
             return Clay_GetScrollOffset()
         }
     }
 
-    pub fn get_element_id(&self, id: &str) -> Clay_ElementId {
-        let id = unsafe {
-            Clay_GetElementId(
-                Clay_String { 
-                    isStaticallyAllocated: false,
-                    length: id.len() as i32, 
-                    chars: id.as_ptr() as *const i8
-                }
-            )
-        };
-
-        id
+    pub fn get_element_id(&self, id: &str) -> ElementId {
+        ElementId::new(id)
     }
 
-    pub fn scroll_container_data(&self, id: Clay_ElementId) -> Option<Clay_ScrollContainerData> {
+    pub fn scroll_container_data(&self, id: impl Into<ElementId>) -> Option<Clay_ScrollContainerData> {
+        self.activate();
         unsafe {
-            Clay_SetCurrentContext(self.context);
-            let scroll_container_data = Clay_GetScrollContainerData(id);
+            let scroll_container_data = Clay_GetScrollContainerData(id.into().into());
 
             if scroll_container_data.found {
                 Some(scroll_container_data)
@@ -247,28 +1208,64 @@ impl<ImageElementData: Debug, CustomElementData: Debug, CustomLayoutSettings> La
 
     /// Returns if the current element you are creating is hovered
     pub fn hovered(&self) -> bool {
+        self.activate();
         unsafe { Clay_Hovered() }
     }
 
-    // pub fn on_click<callback: Fn()>(&mut self, callback_function: callback){
-    //     unsafe {
-    //         Clay_OnHover(Some(LayoutEngine::<(),(),()>::call_back_handler), 0);
-    //     }
-    // }
+    /// The id of the currently open element, also of one clay generated the id for.
+    pub(crate) fn open_element_id(&self) -> u32 {
+        self.activate();
+        unsafe { Clay_GetOpenElementId() }
+    }
+
+    /// Calls `callback` whenever `pointer_state` finds the pointer over the currently open element.
+    /// Callbacks are kept until the next layout begins, so they have to be registered again every frame.
+    pub fn on_hover<F: FnMut(ElementId, PointerData) + 'static>(&mut self, callback: F) {
+        assert!(
+            self.open_element_count > 0 && self.dangling_element_count == 0,
+            "on_hover must be called while a configured element is open!"
+        );
+        self.activate();
+        let id = unsafe { Clay_GetOpenElementId() };
+        self.hover_callbacks.callbacks.entry(id).or_default().push(Box::new(callback));
+
+        unsafe {
+            Clay_OnHover(Some(hover_handler), self.hover_callbacks.as_ptr() as isize);
+        }
+    }
+
+    /// Calls `callback` when the currently open element is clicked, that is when the pointer is pressed
+    /// and released again over it, the same as `Interaction::Click`.
+    /// Callbacks are kept until the next layout begins, so they have to be registered again every frame.
+    pub fn on_click<F: FnMut(ElementId, PointerData) + 'static>(&mut self, callback: F) {
+        assert!(
+            self.open_element_count > 0 && self.dangling_element_count == 0,
+            "on_click must be called while a configured element is open!"
+        );
+        self.activate();
+        let id = unsafe { Clay_GetOpenElementId() };
+        self.hover_callbacks.click_callbacks.entry(id).or_default().push(Box::new(callback));
+    }
 
-    pub fn pointer_over(&self, cfg: Clay_ElementId) -> bool {
-        unsafe { Clay_PointerOver(cfg) }
+    pub fn pointer_over(&self, id: impl Into<ElementId>) -> bool {
+        self.activate();
+        unsafe { Clay_PointerOver(id.into().into()) }
     }
 
-    fn element_data(id: Clay_ElementId) -> Clay_ElementData {
-        unsafe { Clay_GetElementData(id) }
+    fn element_data(&self, id: impl Into<ElementId>) -> Clay_ElementData {
+        self.activate();
+        unsafe { Clay_GetElementData(id.into().into()) }
     }
 
-    pub fn bounding_box(&self, id: Clay_ElementId) -> Option<BoundingBox> {
-        let element_data = Self::element_data(id);
+    pub fn bounding_box(&self, id: impl Into<ElementId>) -> Option<BoundingBox> {
+        let element_data = self.element_data(id);
 
         if element_data.found {
-            Some(element_data.boundingBox.into())
+            let mut bounding_box: BoundingBox = element_data.boundingBox.into();
+            if self.settings.right_to_left {
+                bounding_box.mirror(self.settings.dimensions.0);
+            }
+            Some(bounding_box)
         } else {
             None
         }
@@ -278,7 +1275,9 @@ impl<ImageElementData: Debug, CustomElementData: Debug, CustomLayoutSettings> La
 impl<ImageElementData: Debug, CustomElementData: Debug, CustomLayoutSettings> Drop for LayoutEngine<ImageElementData, CustomElementData, CustomLayoutSettings> {
     fn drop(&mut self) {
         unsafe {
-            Clay_SetCurrentContext(core::ptr::null_mut() as _);
+            if Clay_GetCurrentContext() == self.context {
+                Clay_SetCurrentContext(core::ptr::null_mut() as _);
+            }
         }
     }
 }
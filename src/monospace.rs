@@ -0,0 +1,41 @@
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+use crate::{MeasureText, TextConfig, Vec2};
+
+/// Measures text in fixed size character cells, like a terminal does.
+///
+/// Every grapheme takes up one cell, or two for wide characters like CJK and most emoji.
+/// The font settings of the text are ignored, which makes the results exact and the same everywhere.
+/// Clay still spaces lines by `TextConfig::line_height` when it is set, so set it to 0 to keep lines one cell high.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MonospaceMeasurer {
+    pub cell_width: f32,
+    pub cell_height: f32,
+}
+
+impl MonospaceMeasurer {
+    pub fn new(cell_width: f32, cell_height: f32) -> Self {
+        Self { cell_width, cell_height }
+    }
+
+    /// The number of cells a single line of text takes up.
+    pub fn cells(text: &str) -> usize {
+        text.graphemes(true).map(|grapheme| grapheme.width().min(2)).sum()
+    }
+}
+
+/// Cells of one unit, for layouts that are measured in terminal cells.
+impl Default for MonospaceMeasurer {
+    fn default() -> Self {
+        Self::new(1.0, 1.0)
+    }
+}
+
+impl MeasureText for MonospaceMeasurer {
+    fn measure_text(&mut self, text: &str, _text_config: TextConfig) -> Vec2 {
+        let lines = text.split('\n');
+        let (count, widest) = lines.fold((0, 0), |(count, widest), line| (count + 1, widest.max(Self::cells(line))));
+        Vec2 { x: widest as f32 * self.cell_width, y: count as f32 * self.cell_height }
+    }
+}
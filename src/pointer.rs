@@ -0,0 +1,117 @@
+use std::collections::HashMap;
+
+use crate::bindings::*;
+use crate::ElementId;
+
+/// What the pointer button did since the previous call to `pointer_state`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PointerState {
+    /// The button went down since the previous call.
+    PressedThisFrame,
+    /// The button is held down.
+    Pressed,
+    /// The button went up since the previous call.
+    ReleasedThisFrame,
+    /// The button is up.
+    Released,
+}
+
+impl PointerState {
+    /// The state after a call to `pointer_state`, following the same transitions as clay.
+    pub(crate) fn next(self, is_down: bool) -> PointerState {
+        match (self, is_down) {
+            (PointerState::PressedThisFrame | PointerState::Pressed, true) => PointerState::Pressed,
+            (_, true) => PointerState::PressedThisFrame,
+            (PointerState::ReleasedThisFrame | PointerState::Released, false) => PointerState::Released,
+            (_, false) => PointerState::ReleasedThisFrame,
+        }
+    }
+}
+
+impl From<Clay_PointerDataInteractionState> for PointerState {
+    fn from(value: Clay_PointerDataInteractionState) -> Self {
+        match value {
+            Clay_PointerDataInteractionState::CLAY_POINTER_DATA_PRESSED_THIS_FRAME => PointerState::PressedThisFrame,
+            Clay_PointerDataInteractionState::CLAY_POINTER_DATA_PRESSED => PointerState::Pressed,
+            Clay_PointerDataInteractionState::CLAY_POINTER_DATA_RELEASED_THIS_FRAME => PointerState::ReleasedThisFrame,
+            Clay_PointerDataInteractionState::CLAY_POINTER_DATA_RELEASED => PointerState::Released,
+        }
+    }
+}
+
+/// The position and button state of the pointer.
+#[derive(Debug, Clone, Copy)]
+pub struct PointerData {
+    pub position: Vec2,
+    pub state: PointerState,
+}
+
+impl From<Clay_PointerData> for PointerData {
+    fn from(value: Clay_PointerData) -> Self {
+        PointerData {
+            position: Vec2 { x: value.position.x, y: value.position.y },
+            state: value.state.into(),
+        }
+    }
+}
+
+/// Something the pointer did to an element.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Interaction {
+    /// The pointer moved onto the element.
+    HoverEnter,
+    /// The pointer moved off the element, or the element is gone.
+    HoverLeave,
+    /// The button went down over the element.
+    Press,
+    /// The button went up over the element.
+    Release,
+    /// The button went down and back up over the element.
+    Click,
+}
+
+/// An interaction together with the element it happened to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct InteractionEvent {
+    pub id: ElementId,
+    pub interaction: Interaction,
+}
+
+pub(crate) type HoverCallback = Box<dyn FnMut(ElementId, PointerData)>;
+
+/// The hover and click callbacks registered during the current frame, by element.
+/// The engine shares it with clay, which holds on to a pointer to it.
+pub(crate) struct HoverCallbacks {
+    pub callbacks: HashMap<u32, Vec<HoverCallback>>,
+    /// Called by the engine for `Interaction::Click` events, clay knows nothing about them.
+    pub click_callbacks: HashMap<u32, Vec<HoverCallback>>,
+    /// The button state of the `pointer_state` call being handled.
+    pub state: PointerState,
+    /// The position passed to the `pointer_state` call being handled, before any mirroring.
+    pub position: Vec2,
+}
+
+impl Default for HoverCallbacks {
+    fn default() -> Self {
+        Self {
+            callbacks: HashMap::new(),
+            click_callbacks: HashMap::new(),
+            state: PointerState::Released,
+            position: Vec2::default(),
+        }
+    }
+}
+
+/// Calls every callback registered for the hovered element in the `HoverCallbacks` passed as user data.
+pub(crate) unsafe extern "C" fn hover_handler(element_id: Clay_ElementId, _pointer_data: Clay_PointerData, user_data: isize) {
+    let registry = unsafe { &mut *(user_data as *mut HoverCallbacks) };
+    // Clay calls hover functions before it updates the button state, and only knows the mirrored position
+    // of right to left layouts, so the engine's state is used instead.
+    let pointer_data = PointerData { position: registry.position, state: registry.state };
+
+    if let Some(callbacks) = registry.callbacks.get_mut(&element_id.id) {
+        for callback in callbacks {
+            callback(element_id.into(), pointer_data);
+        }
+    }
+}
@@ -0,0 +1,65 @@
+use std::{fmt::Debug, ops::Deref};
+
+use crate::{LayoutEngine, RenderCommand};
+
+/// The render commands of one finished layout.
+/// A frame borrows the engine that produced it, because its commands point into memory the engine
+/// reuses for the next layout. Holding on to a frame, or any of its commands, across `begin_layout` does not compile.
+///
+/// ```compile_fail
+/// use telera_layout::{LayoutEngine, MonospaceMeasurer};
+///
+/// let mut layout = LayoutEngine::<(), (), ()>::new((80.0, 24.0), MonospaceMeasurer::default());
+/// layout.begin_layout();
+/// let frame = layout.end_layout();
+/// layout.begin_layout();
+/// println!("{:?}", frame.commands());
+/// ```
+pub struct RenderFrame<'engine, ImageElementData: Debug, CustomElementData: Debug, CustomLayoutSettings> {
+    engine: &'engine LayoutEngine<ImageElementData, CustomElementData, CustomLayoutSettings>,
+    commands: Vec<RenderCommand<'engine, ImageElementData, CustomElementData, CustomLayoutSettings>>,
+}
+
+impl<'engine, ImageElementData: Debug, CustomElementData: Debug, CustomLayoutSettings> RenderFrame<'engine, ImageElementData, CustomElementData, CustomLayoutSettings> {
+    pub(crate) fn new(
+        engine: &'engine LayoutEngine<ImageElementData, CustomElementData, CustomLayoutSettings>,
+        commands: Vec<RenderCommand<'engine, ImageElementData, CustomElementData, CustomLayoutSettings>>,
+    ) -> Self {
+        Self { engine, commands }
+    }
+
+    /// The engine that produced this frame, for queries like `bounding_box` while the commands are still in use.
+    pub fn engine(&self) -> &LayoutEngine<ImageElementData, CustomElementData, CustomLayoutSettings> {
+        self.engine
+    }
+
+    pub fn commands(&self) -> &[RenderCommand<'engine, ImageElementData, CustomElementData, CustomLayoutSettings>] {
+        &self.commands
+    }
+}
+
+impl<'engine, ImageElementData: Debug, CustomElementData: Debug, CustomLayoutSettings> Deref for RenderFrame<'engine, ImageElementData, CustomElementData, CustomLayoutSettings> {
+    type Target = [RenderCommand<'engine, ImageElementData, CustomElementData, CustomLayoutSettings>];
+
+    fn deref(&self) -> &Self::Target {
+        &self.commands
+    }
+}
+
+impl<'engine, ImageElementData: Debug, CustomElementData: Debug, CustomLayoutSettings> IntoIterator for RenderFrame<'engine, ImageElementData, CustomElementData, CustomLayoutSettings> {
+    type Item = RenderCommand<'engine, ImageElementData, CustomElementData, CustomLayoutSettings>;
+    type IntoIter = std::vec::IntoIter<Self::Item>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.commands.into_iter()
+    }
+}
+
+impl<'frame, 'engine, ImageElementData: Debug, CustomElementData: Debug, CustomLayoutSettings> IntoIterator for &'frame RenderFrame<'engine, ImageElementData, CustomElementData, CustomLayoutSettings> {
+    type Item = &'frame RenderCommand<'engine, ImageElementData, CustomElementData, CustomLayoutSettings>;
+    type IntoIter = std::slice::Iter<'frame, RenderCommand<'engine, ImageElementData, CustomElementData, CustomLayoutSettings>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.commands.iter()
+    }
+}
@@ -0,0 +1,62 @@
+use std::borrow::Cow;
+use std::fmt::Debug;
+
+use crate::wrap::ContainerItem;
+use crate::TextConfig;
+
+/// A piece of a rich text paragraph with a style of its own, see [`LayoutEngine::add_rich_text`](crate::LayoutEngine::add_rich_text).
+#[derive(Debug, Clone)]
+pub struct Span<'a> {
+    pub text: Cow<'a, str>,
+    pub config: TextConfig,
+}
+
+impl<'a> Span<'a> {
+    pub fn new(text: impl Into<Cow<'a, str>>, config: &TextConfig) -> Self {
+        Self { text: text.into(), config: *config }
+    }
+}
+
+/// Splits the spans into the items of a wrap container, one per word with the whitespace after it.
+/// Parts of a word in different spans stay together in one item, so lines only break between words,
+/// and every new line ends the word before it and breaks the line.
+pub(crate) fn words<'items, ImageElementData: Debug, CustomElementData: Debug, CustomLayoutSettings>(
+    spans: &'items [Span<'_>],
+) -> Vec<ContainerItem<'items, ImageElementData, CustomElementData, CustomLayoutSettings>> {
+    let mut items = Vec::new();
+    let mut word: Vec<(Cow<'items, str>, TextConfig)> = Vec::new();
+
+    for span in spans {
+        for part in span.text.split_inclusive(char::is_whitespace) {
+            if let Some(line) = part.strip_suffix('\n') {
+                if !line.is_empty() {
+                    word.push((Cow::Borrowed(line), span.config));
+                }
+                if !word.is_empty() {
+                    items.push(word_item(std::mem::take(&mut word)));
+                }
+                items.push(ContainerItem::LineBreak { config: span.config });
+                continue;
+            }
+            word.push((Cow::Borrowed(part), span.config));
+            if part.ends_with(char::is_whitespace) {
+                items.push(word_item(std::mem::take(&mut word)));
+            }
+        }
+    }
+    if !word.is_empty() {
+        items.push(word_item(word));
+    }
+    items
+}
+
+fn word_item<'items, ImageElementData: Debug, CustomElementData: Debug, CustomLayoutSettings>(
+    mut parts: Vec<(Cow<'items, str>, TextConfig)>,
+) -> ContainerItem<'items, ImageElementData, CustomElementData, CustomLayoutSettings> {
+    if parts.len() == 1 {
+        let (content, config) = parts.pop().unwrap();
+        ContainerItem::Text { content, config }
+    } else {
+        ContainerItem::TextRun { parts }
+    }
+}
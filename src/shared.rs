@@ -0,0 +1,52 @@
+use std::ops::{Deref, DerefMut};
+use std::os::raw::c_void;
+use std::ptr::NonNull;
+
+/// A value the engine shares with clay, which holds on to a pointer to it and reads or writes it in callbacks.
+/// The value is allocated once and only ever accessed through that same pointer, so the engine's own accesses
+/// never invalidate the one clay holds, the way accesses through a `Box` would. It is freed when dropped.
+pub(crate) struct Shared<T> {
+    value: NonNull<T>,
+}
+
+impl<T> Shared<T> {
+    pub fn new(value: T) -> Self {
+        Self { value: unsafe { NonNull::new_unchecked(Box::into_raw(Box::new(value))) } }
+    }
+
+    /// The pointer to hand to clay.
+    pub fn as_ptr(&self) -> *mut T {
+        self.value.as_ptr()
+    }
+
+    pub fn user_data(&self) -> *mut c_void {
+        self.as_ptr() as *mut c_void
+    }
+}
+
+impl<T: Default> Default for Shared<T> {
+    fn default() -> Self {
+        Self::new(T::default())
+    }
+}
+
+impl<T> Deref for Shared<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        // Clay only uses the pointer while the engine calls into it, never while this borrow is alive.
+        unsafe { self.value.as_ref() }
+    }
+}
+
+impl<T> DerefMut for Shared<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        unsafe { self.value.as_mut() }
+    }
+}
+
+impl<T> Drop for Shared<T> {
+    fn drop(&mut self) {
+        drop(unsafe { Box::from_raw(self.value.as_ptr()) });
+    }
+}
@@ -0,0 +1,119 @@
+use std::fmt::Display;
+
+use crate::bindings::*;
+
+/// How an element is sized along one axis.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Sizing {
+    /// Wraps the children of the element, within the given bounds.
+    Fit { min: f32, max: f32 },
+    /// Takes up the space left over in the parent, within the given bounds.
+    Grow { min: f32, max: f32 },
+    /// A fixed size.
+    Fixed(f32),
+    /// A fraction of the parent's size, from 0.0 to 1.0.
+    Percent(f32),
+}
+
+impl Sizing {
+    /// Wraps the children of the element without bounds.
+    pub fn fit() -> Self {
+        Sizing::Fit { min: 0.0, max: f32::MAX }
+    }
+
+    /// Takes up the space left over in the parent without bounds.
+    pub fn grow() -> Self {
+        Sizing::Grow { min: 0.0, max: f32::MAX }
+    }
+
+    /// Checks that the sizes are finite and not negative, min is not larger than max and percentages are between 0.0 and 1.0.
+    pub fn validate(&self) -> Result<(), SizingError> {
+        match *self {
+            Sizing::Fit { min, max } | Sizing::Grow { min, max } => {
+                check_size(min)?;
+                check_size(max)?;
+                if min > max {
+                    return Err(SizingError::MinGreaterThanMax { min, max });
+                }
+                Ok(())
+            }
+            Sizing::Fixed(size) => check_size(size),
+            Sizing::Percent(percent) => {
+                if percent.is_nan() || !(0.0..=1.0).contains(&percent) {
+                    return Err(SizingError::PercentOutOfRange(percent));
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+pub(crate) fn check_size(size: f32) -> Result<(), SizingError> {
+    if size.is_nan() {
+        return Err(SizingError::NotANumber);
+    }
+    if size.is_infinite() {
+        return Err(SizingError::Infinite(size));
+    }
+    if size < 0.0 {
+        return Err(SizingError::Negative(size));
+    }
+    Ok(())
+}
+
+impl Default for Sizing {
+    fn default() -> Self {
+        Sizing::fit()
+    }
+}
+
+impl From<Sizing> for Clay_SizingAxis {
+    fn from(value: Sizing) -> Self {
+        let (type_, min, max) = match value {
+            Sizing::Fit { min, max } => (Clay__SizingType::CLAY__SIZING_TYPE_FIT, min, max),
+            Sizing::Grow { min, max } => (Clay__SizingType::CLAY__SIZING_TYPE_GROW, min, max),
+            Sizing::Fixed(size) => (Clay__SizingType::CLAY__SIZING_TYPE_FIXED, size, size),
+            Sizing::Percent(percent) => {
+                return Clay_SizingAxis {
+                    type_: Clay__SizingType::CLAY__SIZING_TYPE_PERCENT,
+                    size: Clay_SizingAxis__bindgen_ty_1 { percent },
+                };
+            }
+        };
+        Clay_SizingAxis {
+            type_,
+            size: Clay_SizingAxis__bindgen_ty_1 {
+                minMax: Clay_SizingMinMax { min, max },
+            },
+        }
+    }
+}
+
+/// Why a `Sizing` was rejected.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SizingError {
+    /// A size was negative.
+    Negative(f32),
+    /// A size was NaN.
+    NotANumber,
+    /// A size was infinite.
+    Infinite(f32),
+    /// The minimum size was larger than the maximum size.
+    MinGreaterThanMax { min: f32, max: f32 },
+    /// A percentage was outside of 0.0 to 1.0.
+    PercentOutOfRange(f32),
+}
+
+impl Display for SizingError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SizingError::Negative(size) => write!(f, "sizes can not be negative, got {size}"),
+            SizingError::NotANumber => write!(f, "sizes can not be NaN"),
+            SizingError::Infinite(size) => write!(f, "sizes must be finite, got {size}"),
+            SizingError::MinGreaterThanMax { min, max } => write!(f, "the minimum size {min} is larger than the maximum size {max}"),
+            SizingError::PercentOutOfRange(percent) => write!(f, "percentages must be between 0.0 and 1.0, got {percent}"),
+        }
+    }
+}
+
+impl std::error::Error for SizingError {}
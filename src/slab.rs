@@ -0,0 +1,68 @@
+use std::os::raw::c_void;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// Every slab and every frame gets its own generation, so handles can not be used anywhere else.
+static NEXT_GENERATION: AtomicU64 = AtomicU64::new(1);
+
+fn next_generation() -> u64 {
+    NEXT_GENERATION.fetch_add(1, Ordering::Relaxed)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) struct Handle {
+    generation: u64,
+    index: usize,
+}
+
+/// Refers to image data stored in the engine until the next layout begins.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ImageHandle(pub(crate) Handle);
+
+/// Refers to custom element data stored in the engine until the next layout begins.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CustomElementHandle(pub(crate) Handle);
+
+/// Refers to custom layout settings stored in the engine until the next layout begins.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct LayoutSettingsHandle(pub(crate) Handle);
+
+/// Per frame storage for the values elements point to.
+/// Clay only passes a pointer sized value through, so the engine hands it the index of a value instead of its address.
+#[derive(Debug)]
+pub(crate) struct Slab<T> {
+    values: Vec<T>,
+    generation: u64,
+}
+
+impl<T> Default for Slab<T> {
+    fn default() -> Self {
+        Self { values: Vec::new(), generation: next_generation() }
+    }
+}
+
+impl<T> Slab<T> {
+    pub fn insert(&mut self, value: T) -> Handle {
+        self.values.push(value);
+        Handle { generation: self.generation, index: self.values.len() - 1 }
+    }
+
+    /// The value clay is given for the handle. It is never null, as clay treats null as not set.
+    pub fn user_data(&self, handle: Handle) -> *mut c_void {
+        assert!(
+            handle.generation == self.generation && handle.index < self.values.len(),
+            "Element data handles can only be used with the engine and in the frame that created them!"
+        );
+        (handle.index + 1) as *mut c_void
+    }
+
+    /// Looks up the value for user data created by `user_data`.
+    pub fn get(&self, user_data: *mut c_void) -> Option<&T> {
+        (user_data as usize).checked_sub(1).and_then(|index| self.values.get(index))
+    }
+
+    /// Drops the values of the last frame and invalidates their handles.
+    pub fn reset(&mut self) {
+        self.values.clear();
+        self.generation = next_generation();
+    }
+}
@@ -0,0 +1,277 @@
+use crate::bindings::*;
+use crate::Sizing;
+
+/// A reusable set of element settings.
+///
+/// Only the settings that were set are applied, so a style can be layered on top of a configuration
+/// or another style without resetting anything it does not mention.
+/// The setters are named like the ones on [`ElementConfiguration`](crate::ElementConfiguration).
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct Style {
+    pub(crate) width: Option<Sizing>,
+    pub(crate) height: Option<Sizing>,
+    pub(crate) padding_top: Option<u16>,
+    pub(crate) padding_bottom: Option<u16>,
+    pub(crate) padding_left: Option<u16>,
+    pub(crate) padding_right: Option<u16>,
+    pub(crate) child_gap: Option<u16>,
+    pub(crate) top_to_bottom: Option<bool>,
+    pub(crate) align_children_x: Option<Clay_LayoutAlignmentX>,
+    pub(crate) align_children_y: Option<Clay_LayoutAlignmentY>,
+    pub(crate) color: Option<Color>,
+    pub(crate) radius_top_left: Option<f32>,
+    pub(crate) radius_top_right: Option<f32>,
+    pub(crate) radius_bottom_left: Option<f32>,
+    pub(crate) radius_bottom_right: Option<f32>,
+    pub(crate) border_color: Option<Color>,
+    pub(crate) border_top: Option<u16>,
+    pub(crate) border_bottom: Option<u16>,
+    pub(crate) border_left: Option<u16>,
+    pub(crate) border_right: Option<u16>,
+    pub(crate) border_between_children: Option<u16>,
+    pub(crate) aspect_ratio: Option<f32>,
+}
+
+impl Style {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Combines two styles. Settings of `overrides` win, settings it leaves unset are kept from `self`.
+    pub fn merge(&self, overrides: &Style) -> Style {
+        Style {
+            width: overrides.width.or(self.width),
+            height: overrides.height.or(self.height),
+            padding_top: overrides.padding_top.or(self.padding_top),
+            padding_bottom: overrides.padding_bottom.or(self.padding_bottom),
+            padding_left: overrides.padding_left.or(self.padding_left),
+            padding_right: overrides.padding_right.or(self.padding_right),
+            child_gap: overrides.child_gap.or(self.child_gap),
+            top_to_bottom: overrides.top_to_bottom.or(self.top_to_bottom),
+            align_children_x: overrides.align_children_x.or(self.align_children_x),
+            align_children_y: overrides.align_children_y.or(self.align_children_y),
+            color: overrides.color.or(self.color),
+            radius_top_left: overrides.radius_top_left.or(self.radius_top_left),
+            radius_top_right: overrides.radius_top_right.or(self.radius_top_right),
+            radius_bottom_left: overrides.radius_bottom_left.or(self.radius_bottom_left),
+            radius_bottom_right: overrides.radius_bottom_right.or(self.radius_bottom_right),
+            border_color: overrides.border_color.or(self.border_color),
+            border_top: overrides.border_top.or(self.border_top),
+            border_bottom: overrides.border_bottom.or(self.border_bottom),
+            border_left: overrides.border_left.or(self.border_left),
+            border_right: overrides.border_right.or(self.border_right),
+            border_between_children: overrides.border_between_children.or(self.border_between_children),
+            aspect_ratio: overrides.aspect_ratio.or(self.aspect_ratio),
+        }
+    }
+
+    pub fn width(&mut self, sizing: Sizing) -> &mut Self {
+        self.width = Some(sizing);
+        self
+    }
+    pub fn height(&mut self, sizing: Sizing) -> &mut Self {
+        self.height = Some(sizing);
+        self
+    }
+    pub fn padding_all(&mut self, amount: u16) -> &mut Self {
+        self.padding_top = Some(amount);
+        self.padding_bottom = Some(amount);
+        self.padding_left = Some(amount);
+        self.padding_right = Some(amount);
+        self
+    }
+    pub fn padding_top(&mut self, amount: u16) -> &mut Self {
+        self.padding_top = Some(amount);
+        self
+    }
+    pub fn padding_bottom(&mut self, amount: u16) -> &mut Self {
+        self.padding_bottom = Some(amount);
+        self
+    }
+    pub fn padding_left(&mut self, amount: u16) -> &mut Self {
+        self.padding_left = Some(amount);
+        self
+    }
+    pub fn padding_right(&mut self, amount: u16) -> &mut Self {
+        self.padding_right = Some(amount);
+        self
+    }
+    pub fn child_gap(&mut self, amount: u16) -> &mut Self {
+        self.child_gap = Some(amount);
+        self
+    }
+    pub fn direction(&mut self, top_to_bottom: bool) -> &mut Self {
+        self.top_to_bottom = Some(top_to_bottom);
+        self
+    }
+    pub fn align_children_x_center(&mut self) -> &mut Self {
+        self.align_children_x = Some(Clay_LayoutAlignmentX::CLAY_ALIGN_X_CENTER);
+        self
+    }
+    pub fn align_children_x_left(&mut self) -> &mut Self {
+        self.align_children_x = Some(Clay_LayoutAlignmentX::CLAY_ALIGN_X_LEFT);
+        self
+    }
+    pub fn align_children_x_right(&mut self) -> &mut Self {
+        self.align_children_x = Some(Clay_LayoutAlignmentX::CLAY_ALIGN_X_RIGHT);
+        self
+    }
+    pub fn align_children_y_center(&mut self) -> &mut Self {
+        self.align_children_y = Some(Clay_LayoutAlignmentY::CLAY_ALIGN_Y_CENTER);
+        self
+    }
+    pub fn align_children_y_top(&mut self) -> &mut Self {
+        self.align_children_y = Some(Clay_LayoutAlignmentY::CLAY_ALIGN_Y_TOP);
+        self
+    }
+    pub fn align_children_y_bottom(&mut self) -> &mut Self {
+        self.align_children_y = Some(Clay_LayoutAlignmentY::CLAY_ALIGN_Y_BOTTOM);
+        self
+    }
+    pub fn color(&mut self, color: Color) -> &mut Self {
+        self.color = Some(color);
+        self
+    }
+    pub fn radius_all(&mut self, radius: f32) -> &mut Self {
+        self.radius_top_left = Some(radius);
+        self.radius_top_right = Some(radius);
+        self.radius_bottom_left = Some(radius);
+        self.radius_bottom_right = Some(radius);
+        self
+    }
+    pub fn radius_top_left(&mut self, radius: f32) -> &mut Self {
+        self.radius_top_left = Some(radius);
+        self
+    }
+    pub fn radius_top_right(&mut self, radius: f32) -> &mut Self {
+        self.radius_top_right = Some(radius);
+        self
+    }
+    pub fn radius_bottom_left(&mut self, radius: f32) -> &mut Self {
+        self.radius_bottom_left = Some(radius);
+        self
+    }
+    pub fn radius_bottom_right(&mut self, radius: f32) -> &mut Self {
+        self.radius_bottom_right = Some(radius);
+        self
+    }
+    pub fn border_color(&mut self, color: Color) -> &mut Self {
+        self.border_color = Some(color);
+        self
+    }
+    pub fn border_all(&mut self, width: u16) -> &mut Self {
+        self.border_top = Some(width);
+        self.border_bottom = Some(width);
+        self.border_left = Some(width);
+        self.border_right = Some(width);
+        self.border_between_children = Some(width);
+        self
+    }
+    pub fn border_top(&mut self, width: u16) -> &mut Self {
+        self.border_top = Some(width);
+        self
+    }
+    pub fn border_left(&mut self, width: u16) -> &mut Self {
+        self.border_left = Some(width);
+        self
+    }
+    pub fn border_bottom(&mut self, width: u16) -> &mut Self {
+        self.border_bottom = Some(width);
+        self
+    }
+    pub fn border_right(&mut self, width: u16) -> &mut Self {
+        self.border_right = Some(width);
+        self
+    }
+    pub fn border_between_children(&mut self, width: u16) -> &mut Self {
+        self.border_between_children = Some(width);
+        self
+    }
+    pub fn aspect_ratio(&mut self, ratio: f32) -> &mut Self {
+        self.aspect_ratio = Some(ratio);
+        self
+    }
+    pub fn end(self) -> Self {
+        self
+    }
+}
+
+/// A reusable set of text settings.
+/// Works like [`Style`], but for [`TextConfig`](crate::TextConfig).
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct TextStyle {
+    pub(crate) color: Option<Color>,
+    pub(crate) font_id: Option<u16>,
+    pub(crate) font_size: Option<u16>,
+    pub(crate) letter_spacing: Option<u16>,
+    pub(crate) line_height: Option<u16>,
+    pub(crate) wrap_mode: Option<Clay_TextElementConfigWrapMode>,
+    pub(crate) alignment: Option<Clay_TextAlignment>,
+}
+
+impl TextStyle {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Combines two styles. Settings of `overrides` win, settings it leaves unset are kept from `self`.
+    pub fn merge(&self, overrides: &TextStyle) -> TextStyle {
+        TextStyle {
+            color: overrides.color.or(self.color),
+            font_id: overrides.font_id.or(self.font_id),
+            font_size: overrides.font_size.or(self.font_size),
+            letter_spacing: overrides.letter_spacing.or(self.letter_spacing),
+            line_height: overrides.line_height.or(self.line_height),
+            wrap_mode: overrides.wrap_mode.or(self.wrap_mode),
+            alignment: overrides.alignment.or(self.alignment),
+        }
+    }
+
+    pub fn color(&mut self, color: Color) -> &mut Self {
+        self.color = Some(color);
+        self
+    }
+    pub fn font_id(&mut self, id: u16) -> &mut Self {
+        self.font_id = Some(id);
+        self
+    }
+    pub fn font_size(&mut self, size: u16) -> &mut Self {
+        self.font_size = Some(size);
+        self
+    }
+    pub fn letter_spacing(&mut self, spacing: u16) -> &mut Self {
+        self.letter_spacing = Some(spacing);
+        self
+    }
+    pub fn line_height(&mut self, height: u16) -> &mut Self {
+        self.line_height = Some(height);
+        self
+    }
+    pub fn wrap_mode_words(&mut self) -> &mut Self {
+        self.wrap_mode = Some(Clay_TextElementConfigWrapMode::CLAY_TEXT_WRAP_WORDS);
+        self
+    }
+    pub fn wrap_mode_new_lines(&mut self) -> &mut Self {
+        self.wrap_mode = Some(Clay_TextElementConfigWrapMode::CLAY_TEXT_WRAP_NEWLINES);
+        self
+    }
+    pub fn wrap_mode_none(&mut self) -> &mut Self {
+        self.wrap_mode = Some(Clay_TextElementConfigWrapMode::CLAY_TEXT_WRAP_NONE);
+        self
+    }
+    pub fn alignment_left(&mut self) -> &mut Self {
+        self.alignment = Some(Clay_TextAlignment::CLAY_TEXT_ALIGN_LEFT);
+        self
+    }
+    pub fn alignment_right(&mut self) -> &mut Self {
+        self.alignment = Some(Clay_TextAlignment::CLAY_TEXT_ALIGN_RIGHT);
+        self
+    }
+    pub fn alignment_center(&mut self) -> &mut Self {
+        self.alignment = Some(Clay_TextAlignment::CLAY_TEXT_ALIGN_CENTER);
+        self
+    }
+    pub fn end(self) -> Self {
+        self
+    }
+}
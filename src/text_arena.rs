@@ -0,0 +1,55 @@
+use std::borrow::Cow;
+
+/// The size of the buffers borrowed strings are copied into.
+const CHUNK_SIZE: usize = 4096;
+
+/// Per frame storage for the text handed to clay.
+/// Clay keeps pointers to text until the next layout begins, so nothing stored here is ever moved or
+/// reallocated before `reset`. Borrowed strings are copied into fixed size chunks, owned strings are kept as they are.
+#[derive(Debug, Default)]
+pub(crate) struct TextArena {
+    chunks: Vec<String>,
+    current_chunk: usize,
+    owned: Vec<String>,
+}
+
+impl TextArena {
+    /// Stores the text for the rest of the frame and returns the stored copy.
+    pub fn store(&mut self, text: Cow<'_, str>) -> &str {
+        match text {
+            Cow::Owned(text) => {
+                // Moving a String does not move its heap buffer.
+                self.owned.push(text);
+                self.owned.last().unwrap()
+            }
+            Cow::Borrowed(text) => self.copy(text),
+        }
+    }
+
+    fn copy(&mut self, text: &str) -> &str {
+        while self.current_chunk < self.chunks.len() {
+            let chunk = &self.chunks[self.current_chunk];
+            if chunk.capacity() - chunk.len() >= text.len() {
+                break;
+            }
+            self.current_chunk += 1;
+        }
+        if self.current_chunk == self.chunks.len() {
+            self.chunks.push(String::with_capacity(CHUNK_SIZE.max(text.len())));
+        }
+
+        let chunk = &mut self.chunks[self.current_chunk];
+        let start = chunk.len();
+        chunk.push_str(text);
+        &chunk[start..]
+    }
+
+    /// Forgets the text of the last frame while keeping the chunks around for reuse.
+    pub fn reset(&mut self) {
+        for chunk in &mut self.chunks {
+            chunk.clear();
+        }
+        self.current_chunk = 0;
+        self.owned.clear();
+    }
+}
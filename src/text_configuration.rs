@@ -1,6 +1,8 @@
+use std::any::Any;
 use std::os::raw::c_void;
 
 use crate::bindings::*;
+use crate::TextStyle;
 
 /// Configuration settings for rendering text elements.
 #[derive(Debug, Clone, Copy)]
@@ -92,6 +94,18 @@ impl TextConfig {
         self
     }
 
+    /// Applies every setting the style sets and leaves the others as they are.
+    pub fn apply(&mut self, style: &TextStyle) -> &mut Self {
+        if let Some(color) = style.color { self.color = color; }
+        if let Some(id) = style.font_id { self.font_id = id; }
+        if let Some(size) = style.font_size { self.font_size = size; }
+        if let Some(spacing) = style.letter_spacing { self.letter_spacing = spacing; }
+        if let Some(height) = style.line_height { self.line_height = height; }
+        if let Some(wrap_mode) = style.wrap_mode { self.wrap_mode = wrap_mode; }
+        if let Some(alignment) = style.alignment { self.alignment = alignment; }
+        self
+    }
+
     pub fn parse(&mut self){}
 
     /// Finalizes the text configuration
@@ -144,10 +158,17 @@ impl From<Clay_TextElementConfig> for TextConfig {
     }
 }
 
-pub trait MeasureText{
+/// Measures text for the engine. Renderers are `Any`, so the engine can hand out the one it owns by its type.
+pub trait MeasureText: Any {
     fn measure_text(&mut self, text: &str, text_config: TextConfig) -> Vec2;
 }
 
+impl<T: MeasureText + ?Sized> MeasureText for Box<T> {
+    fn measure_text(&mut self, text: &str, text_config: TextConfig) -> Vec2 {
+        (**self).measure_text(text, text_config)
+    }
+}
+
 pub unsafe extern "C" fn measure_text_c_callback<'a, T>(
     text_slice: Clay_StringSlice,
     config: *mut Clay_TextElementConfig,
@@ -0,0 +1,108 @@
+use std::any::Any;
+use std::collections::HashMap;
+
+use crate::{MeasureText, TextConfig, Vec2};
+
+/// How much text measuring the current frame took, see [`LayoutEngine::text_measurement_stats`](crate::LayoutEngine::text_measurement_stats).
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct TextMeasurementStats {
+    /// Calls to the text renderer.
+    pub measure_calls: u32,
+    /// Text elements whose measurement clay had cached from an earlier frame.
+    pub cache_hits: u32,
+    /// Text elements clay had to measure word by word.
+    pub cache_misses: u32,
+}
+
+/// The settings measurements depend on. Clay caches by the same settings, apart from the line height.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct FontSettings {
+    font_id: u16,
+    font_size: u16,
+    letter_spacing: u16,
+    line_height: u16,
+}
+
+impl From<&TextConfig> for FontSettings {
+    fn from(config: &TextConfig) -> Self {
+        Self {
+            font_id: config.font_id,
+            font_size: config.font_size,
+            letter_spacing: config.letter_spacing,
+            line_height: config.line_height,
+        }
+    }
+}
+
+/// The text renderer of an engine, with the words it measured.
+/// Clay's own cache can only be reset as a whole, so after that these answer clay for every font that is still valid.
+pub(crate) struct MeasurementCache {
+    renderer: Box<dyn MeasureText>,
+    measurements: HashMap<FontSettings, HashMap<String, Vec2>>,
+    len: usize,
+    /// Every measurement asked for, cached or not.
+    pub lookups: u32,
+    pub stats: TextMeasurementStats,
+}
+
+impl MeasurementCache {
+    pub fn new(renderer: Box<dyn MeasureText>) -> Self {
+        Self {
+            renderer,
+            measurements: HashMap::new(),
+            len: 0,
+            lookups: 0,
+            stats: TextMeasurementStats::default(),
+        }
+    }
+
+    pub fn renderer_mut<T: MeasureText>(&mut self) -> Option<&mut T> {
+        (&mut *self.renderer as &mut dyn Any).downcast_mut()
+    }
+
+    pub fn set_renderer(&mut self, renderer: Box<dyn MeasureText>) {
+        self.renderer = renderer;
+        self.clear();
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn clear(&mut self) {
+        self.measurements.clear();
+        self.len = 0;
+    }
+
+    /// Measures text with the renderer alone, for text that is not measured for clay, like the items of
+    /// containers the engine arranges. It neither counts toward the stats nor fills the cache with whole texts.
+    pub fn measure_uncached(&mut self, text: &str, text_config: TextConfig) -> Vec2 {
+        self.renderer.measure_text(text, text_config)
+    }
+
+    pub fn remove_font(&mut self, font_id: u16) {
+        let len = &mut self.len;
+        self.measurements.retain(|settings, words| {
+            let keep = settings.font_id != font_id;
+            if !keep {
+                *len -= words.len();
+            }
+            keep
+        });
+    }
+}
+
+impl MeasureText for MeasurementCache {
+    fn measure_text(&mut self, text: &str, text_config: TextConfig) -> Vec2 {
+        self.lookups += 1;
+        let words = self.measurements.entry(FontSettings::from(&text_config)).or_default();
+        if let Some(&size) = words.get(text) {
+            return size;
+        }
+        self.stats.measure_calls += 1;
+        let size = self.renderer.measure_text(text, text_config);
+        words.insert(text.to_owned(), size);
+        self.len += 1;
+        size
+    }
+}
@@ -0,0 +1,125 @@
+use std::borrow::Cow;
+use std::fmt::Debug;
+use std::ops::Range;
+
+use crate::{ElementConfiguration, ElementId, LayoutEngine, Sizing, TextConfig};
+
+type Children<'items, ImageElementData, CustomElementData, CustomLayoutSettings> =
+    Box<dyn FnOnce(&mut LayoutEngine<ImageElementData, CustomElementData, CustomLayoutSettings>) + 'items>;
+
+/// An item of a container the engine arranges itself, like a wrap container or a grid.
+pub(crate) enum ContainerItem<'items, ImageElementData: Debug, CustomElementData: Debug, CustomLayoutSettings> {
+    Text {
+        content: Cow<'items, str>,
+        config: TextConfig,
+    },
+    Element {
+        config: Box<ElementConfiguration>,
+        children: Children<'items, ImageElementData, CustomElementData, CustomLayoutSettings>,
+    },
+    /// Texts that are laid out next to each other without a gap, like the parts of a word in rich text.
+    TextRun {
+        parts: Vec<(Cow<'items, str>, TextConfig)>,
+    },
+    /// Ends the line it is on, like a new line in rich text. A line holding nothing else is as high as a line of text.
+    LineBreak {
+        config: TextConfig,
+    },
+}
+
+impl<ImageElementData: Debug, CustomElementData: Debug, CustomLayoutSettings> ContainerItem<'_, ImageElementData, CustomElementData, CustomLayoutSettings> {
+    /// Gives an element item without an id one from its position in the container,
+    /// so it can be found again in the next frame.
+    pub fn identify(&mut self, container: u32, index: u32) {
+        if let ContainerItem::Element { config, .. } = self
+            && config.get_id() == 0
+        {
+            config.id(ElementId::container_item(container, index));
+        }
+    }
+
+    pub fn declare(self, layout: &mut LayoutEngine<ImageElementData, CustomElementData, CustomLayoutSettings>) {
+        match self {
+            ContainerItem::Text { content, config } => layout.text(content, &config),
+            ContainerItem::Element { config, children } => {
+                layout.element(&config, children);
+            }
+            ContainerItem::TextRun { parts } => {
+                layout.element(&ElementConfiguration::new(), |layout| {
+                    for (content, config) in parts {
+                        layout.text(content, &config);
+                    }
+                });
+            }
+            ContainerItem::LineBreak { config } => {
+                let height = layout.text_renderer.measure_uncached(" ", config).y;
+                layout.element(&ElementConfiguration::new().width(Sizing::Fixed(0.0)).height(Sizing::Fixed(height)).end(), |_| {});
+            }
+        }
+    }
+}
+
+/// The items of a wrap container, see [`LayoutEngine::wrap`].
+pub struct Wrap<'items, ImageElementData: Debug, CustomElementData: Debug, CustomLayoutSettings> {
+    pub(crate) items: Vec<ContainerItem<'items, ImageElementData, CustomElementData, CustomLayoutSettings>>,
+}
+
+impl<'items, ImageElementData: Debug, CustomElementData: Debug, CustomLayoutSettings> Wrap<'items, ImageElementData, CustomElementData, CustomLayoutSettings> {
+    pub(crate) fn new() -> Self {
+        Self { items: Vec::new() }
+    }
+
+    /// Adds a text item. Its size is measured with the engine's text renderer.
+    pub fn text(&mut self, content: impl Into<Cow<'items, str>>, config: &TextConfig) -> &mut Self {
+        self.items.push(ContainerItem::Text { content: content.into(), config: *config });
+        self
+    }
+
+    /// Adds an element item. Its children are declared by `children`.
+    pub fn element<F>(&mut self, config: &ElementConfiguration, children: F) -> &mut Self
+    where
+        F: FnOnce(&mut LayoutEngine<ImageElementData, CustomElementData, CustomLayoutSettings>) + 'items,
+    {
+        self.items.push(ContainerItem::Element { config: Box::new(*config), children: Box::new(children) });
+        self
+    }
+}
+
+/// An item of a wrap container as far as breaking lines is concerned.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub(crate) struct LineItem {
+    /// The size along the lines.
+    pub size: f32,
+    /// The part of `size` taken up by whitespace at the end, which may hang past the end of a line.
+    pub trailing_whitespace: f32,
+    /// Whether the line ends after the item.
+    pub line_break: bool,
+}
+
+/// Splits the items into lines no longer than `available`, greedily.
+/// An item larger than `available` gets a line of its own.
+pub(crate) fn break_lines(items: &[LineItem], available: f32, gap: f32) -> Vec<Range<usize>> {
+    let mut lines = Vec::new();
+    let mut start = 0;
+    let mut length = 0.0;
+
+    for (index, item) in items.iter().enumerate() {
+        if index > start && !item.line_break && length + gap + item.size - item.trailing_whitespace > available {
+            lines.push(start..index);
+            start = index;
+            length = item.size;
+        } else if index == start {
+            length = item.size;
+        } else {
+            length += gap + item.size;
+        }
+        if item.line_break {
+            lines.push(start..index + 1);
+            start = index + 1;
+        }
+    }
+    if start < items.len() {
+        lines.push(start..items.len());
+    }
+    lines
+}
@@ -0,0 +1,51 @@
+use serial_test::serial;
+use telera_layout::{
+    ElementConfiguration, LayoutEngine, LayoutEngineBuilder, LayoutError, DEFAULT_MAX_ELEMENT_COUNT,
+    DEFAULT_MAX_MEASURE_TEXT_CACHE_WORD_COUNT,
+};
+
+mod common;
+use common::FixedMeasurer;
+
+fn declare_elements(layout: &mut LayoutEngine<(), (), ()>, count: usize) {
+    layout.begin_layout();
+    for _ in 0..count {
+        layout.element(&ElementConfiguration::new(), |_| {});
+    }
+    layout.end_layout();
+}
+
+#[test]
+#[serial]
+fn engines_use_the_default_capacities() {
+    let layout = LayoutEngine::<(), (), ()>::new((800.0, 600.0), FixedMeasurer);
+    assert_eq!(layout.max_element_count(), DEFAULT_MAX_ELEMENT_COUNT);
+    assert_eq!(layout.max_measure_text_cache_word_count(), DEFAULT_MAX_MEASURE_TEXT_CACHE_WORD_COUNT);
+}
+
+#[test]
+#[serial]
+fn engines_use_the_configured_capacities() {
+    let mut small = LayoutEngineBuilder::new()
+        .dimensions(100.0, 100.0)
+        .max_element_count(10)
+        .max_measure_text_cache_word_count(64)
+        .auto_grow(false)
+        .build::<(), (), ()>(FixedMeasurer);
+    assert_eq!(small.max_element_count(), 10);
+    assert_eq!(small.max_measure_text_cache_word_count(), 64);
+
+    declare_elements(&mut small, 20);
+    assert!(small.errors().contains(&LayoutError::ElementsCapacityExceeded));
+
+    let mut large = LayoutEngineBuilder::new().max_element_count(20000).build::<(), (), ()>(FixedMeasurer);
+    assert_eq!(large.max_element_count(), 20000);
+    declare_elements(&mut large, 15000);
+    assert!(large.errors().is_empty());
+}
+
+#[test]
+#[should_panic(expected = "must be at least 32")]
+fn word_caches_need_a_bucket() {
+    LayoutEngineBuilder::new().max_measure_text_cache_word_count(16).build::<(), (), ()>(FixedMeasurer);
+}
@@ -0,0 +1,67 @@
+use std::{cell::RefCell, rc::Rc};
+
+use serial_test::serial;
+use telera_layout::{ElementConfiguration, ElementId, LayoutEngine, Sizing, PointerState};
+
+mod common;
+use common::FixedMeasurer;
+
+fn declare(layout: &mut LayoutEngine<(), (), ()>, hovers: &Rc<RefCell<Vec<(ElementId, PointerState)>>>, clicks: &Rc<RefCell<u32>>) {
+    let button = ElementConfiguration::new().id("button").width(Sizing::Fixed(100.0)).height(Sizing::Fixed(50.0)).end();
+    let other = ElementConfiguration::new().id("other").width(Sizing::Fixed(100.0)).height(Sizing::Fixed(50.0)).end();
+
+    layout.begin_layout();
+    layout.element(&button, |layout| {
+        let hovers = hovers.clone();
+        layout.on_hover(move |id, pointer| hovers.borrow_mut().push((id, pointer.state)));
+        let clicks = clicks.clone();
+        layout.on_click(move |_, _| *clicks.borrow_mut() += 1);
+    });
+    layout.element(&other, |_| {});
+    layout.end_layout();
+}
+
+#[test]
+#[serial]
+fn callbacks_are_called_for_the_element_under_the_pointer() {
+    let hovers = Rc::new(RefCell::new(Vec::new()));
+    let clicks = Rc::new(RefCell::new(0));
+    let mut layout = LayoutEngine::<(), (), ()>::new((800.0, 600.0), FixedMeasurer);
+
+    declare(&mut layout, &hovers, &clicks);
+    layout.pointer_state(150.0, 10.0, false);
+    assert!(hovers.borrow().is_empty());
+
+    declare(&mut layout, &hovers, &clicks);
+    layout.pointer_state(10.0, 10.0, false);
+    declare(&mut layout, &hovers, &clicks);
+    layout.pointer_state(10.0, 10.0, true);
+    declare(&mut layout, &hovers, &clicks);
+    layout.pointer_state(10.0, 10.0, true);
+    assert_eq!(*clicks.borrow(), 0);
+    declare(&mut layout, &hovers, &clicks);
+    layout.pointer_state(10.0, 10.0, false);
+
+    assert_eq!(*hovers.borrow(), vec![
+        (ElementId::new("button"), PointerState::Released),
+        (ElementId::new("button"), PointerState::PressedThisFrame),
+        (ElementId::new("button"), PointerState::Pressed),
+        (ElementId::new("button"), PointerState::ReleasedThisFrame),
+    ]);
+    assert_eq!(*clicks.borrow(), 1);
+}
+
+#[test]
+#[serial]
+fn releasing_elsewhere_is_not_a_click() {
+    let hovers = Rc::new(RefCell::new(Vec::new()));
+    let clicks = Rc::new(RefCell::new(0));
+    let mut layout = LayoutEngine::<(), (), ()>::new((800.0, 600.0), FixedMeasurer);
+
+    declare(&mut layout, &hovers, &clicks);
+    layout.pointer_state(10.0, 10.0, true);
+    declare(&mut layout, &hovers, &clicks);
+    layout.pointer_state(110.0, 10.0, false);
+
+    assert_eq!(*clicks.borrow(), 0);
+}
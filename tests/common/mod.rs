@@ -0,0 +1,10 @@
+use telera_layout::{MeasureText, TextConfig, Vec2};
+
+/// Measures every character as 10 wide and text as tall as its line height.
+pub struct FixedMeasurer;
+
+impl MeasureText for FixedMeasurer {
+    fn measure_text(&mut self, text: &str, text_config: TextConfig) -> Vec2 {
+        Vec2 { x: text.len() as f32 * 10.0, y: text_config.line_height as f32 }
+    }
+}
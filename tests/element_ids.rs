@@ -0,0 +1,45 @@
+use serial_test::serial;
+use telera_layout::{ElementConfiguration, ElementId, LayoutEngine, Sizing, TextConfig};
+
+mod common;
+use common::FixedMeasurer;
+
+#[test]
+fn ids_are_stable_and_distinct() {
+    assert_eq!(ElementId::new("row"), ElementId::from("row"));
+    assert_eq!(ElementId::indexed("row", 0), ElementId::new("row"));
+    assert_ne!(ElementId::indexed("row", 1), ElementId::indexed("row", 2));
+    assert_ne!(ElementId::local("left", "label"), ElementId::local("right", "label"));
+    assert_ne!(ElementId::local("left", "label"), ElementId::new("label"));
+}
+
+#[test]
+#[serial]
+fn indexed_rows_can_be_addressed() {
+    let mut layout = LayoutEngine::<(), (), ()>::new((800.0, 600.0), FixedMeasurer);
+    let list = ElementConfiguration::new().id("list").width(Sizing::grow()).height(Sizing::grow()).direction(true).end();
+
+    layout.begin_layout();
+    let list_id = layout.element(&list, |layout| {
+        for index in 0..5 {
+            let row = ElementConfiguration::new().id(ElementId::indexed("row", index)).width(Sizing::grow()).height(Sizing::Fixed(20.0)).end();
+            layout.element(&row, |layout| {
+                let label = ElementConfiguration::new().id(ElementId::local(ElementId::indexed("row", index), "label")).end();
+                layout.element(&label, |layout| {
+                    layout.text(format!("row {index}"), &TextConfig::new());
+                });
+            });
+        }
+    });
+    layout.end_layout();
+
+    assert_eq!(list_id, ElementId::new("list"));
+    assert!(layout.errors().is_empty());
+    for index in 0..5 {
+        let row = layout.bounding_box(ElementId::indexed("row", index)).unwrap();
+        assert_eq!(row.y, index as f32 * 20.0);
+
+        let label = layout.bounding_box(ElementId::local(ElementId::indexed("row", index), "label")).unwrap();
+        assert_eq!(label.y, row.y);
+    }
+}
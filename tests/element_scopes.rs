@@ -0,0 +1,29 @@
+use serial_test::serial;
+use telera_layout::{ElementConfiguration, LayoutEngine, Sizing};
+
+mod common;
+use common::FixedMeasurer;
+
+#[test]
+#[serial]
+fn nested_scopes_close_their_elements_in_order() {
+    let mut layout = LayoutEngine::<(), (), ()>::new((800.0, 600.0), FixedMeasurer);
+    let fixed = |id: &str| ElementConfiguration::new().id(id).width(Sizing::Fixed(50.0)).height(Sizing::Fixed(20.0)).end();
+
+    layout.begin_layout();
+    layout.element(&ElementConfiguration::new().id("outer").direction(true).end(), |layout| {
+        layout.element(&ElementConfiguration::new().id("inner").end(), |layout| {
+            layout.element(&fixed("first"), |_| {});
+            layout.element(&fixed("second"), |_| {});
+        });
+        layout.element(&fixed("after"), |_| {});
+    });
+    layout.end_layout();
+
+    let inner = layout.bounding_box("inner").unwrap();
+    assert_eq!((inner.width, inner.height), (100.0, 20.0));
+    let after = layout.bounding_box("after").unwrap();
+    assert_eq!((after.x, after.y), (0.0, 20.0));
+    let outer = layout.bounding_box("outer").unwrap();
+    assert_eq!((outer.width, outer.height), (100.0, 40.0));
+}
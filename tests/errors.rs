@@ -0,0 +1,23 @@
+use serial_test::serial;
+use telera_layout::{ElementConfiguration, LayoutEngine, LayoutError};
+
+mod common;
+use common::FixedMeasurer;
+
+#[test]
+#[serial]
+fn duplicate_ids_are_collected_until_the_next_layout() {
+    let mut layout = LayoutEngine::<(), (), ()>::new((800.0, 600.0), FixedMeasurer);
+    let twin = ElementConfiguration::new().id("twin").end();
+
+    layout.begin_layout();
+    layout.element(&twin, |_| {});
+    layout.element(&twin, |_| {});
+    layout.end_layout();
+    assert_eq!(layout.errors(), [LayoutError::DuplicateId]);
+
+    layout.begin_layout();
+    layout.element(&twin, |_| {});
+    layout.end_layout();
+    assert!(layout.errors().is_empty());
+}
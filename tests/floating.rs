@@ -0,0 +1,66 @@
+use serial_test::serial;
+use telera_layout::{AttachPoint, AttachTo, Color, ElementConfiguration, ElementId, Floating, FloatingClip, LayoutEngine, RenderCommand, Sizing};
+
+mod common;
+use common::FixedMeasurer;
+
+fn dropdown_commands(clip: FloatingClip) -> Vec<String> {
+    let mut layout = LayoutEngine::<(), (), ()>::new((800.0, 600.0), FixedMeasurer);
+    let container = ElementConfiguration::new()
+        .id("container")
+        .width(Sizing::Fixed(100.0))
+        .height(Sizing::Fixed(100.0))
+        .scroll(true, false, Default::default())
+        .end();
+    let dropdown = ElementConfiguration::new()
+        .id("dropdown")
+        .width(Sizing::Fixed(50.0))
+        .height(Sizing::Fixed(200.0))
+        .color(Color::default())
+        .floating(Floating::new().offset(0.0, 80.0).clip(clip).end())
+        .end();
+
+    layout.begin_layout();
+    layout.element(&container, |layout| {
+        layout.element(&dropdown, |_| {});
+    });
+    let commands = layout.end_layout();
+    commands.iter().map(|command| match command {
+        RenderCommand::ScissorStart(_) => "scissor start".to_string(),
+        RenderCommand::ScissorEnd => "scissor end".to_string(),
+        RenderCommand::Rectangle(_) => "rectangle".to_string(),
+        _ => "other".to_string(),
+    }).collect()
+}
+
+#[test]
+#[serial]
+fn floating_elements_can_be_clipped_by_their_parent() {
+    assert_eq!(dropdown_commands(FloatingClip::None), ["scissor start", "scissor end", "rectangle"]);
+    assert_eq!(dropdown_commands(FloatingClip::AttachedParent), ["scissor start", "scissor end", "scissor start", "rectangle", "scissor end"]);
+}
+
+#[test]
+#[serial]
+fn floating_elements_attach_to_other_elements() {
+    let mut layout = LayoutEngine::<(), (), ()>::new((800.0, 600.0), FixedMeasurer);
+    let anchor = ElementConfiguration::new().id("anchor").width(Sizing::Fixed(100.0)).height(Sizing::Fixed(40.0)).end();
+    let tooltip = ElementConfiguration::new()
+        .id("tooltip")
+        .width(Sizing::Fixed(60.0))
+        .height(Sizing::Fixed(20.0))
+        .floating(Floating::new()
+            .attach_to(AttachTo::Element(ElementId::new("anchor")))
+            .attach_points(AttachPoint::TopCenter, AttachPoint::BottomCenter)
+            .end())
+        .end();
+
+    layout.begin_layout();
+    layout.element(&anchor, |_| {});
+    layout.element(&tooltip, |_| {});
+    layout.end_layout();
+
+    let tooltip = layout.bounding_box("tooltip").unwrap();
+    assert_eq!((tooltip.x, tooltip.y), (20.0, 40.0));
+    assert!(layout.errors().is_empty());
+}
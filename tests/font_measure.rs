@@ -0,0 +1,123 @@
+#![cfg(feature = "font_measure")]
+
+use serial_test::serial;
+use telera_layout::{ElementConfiguration, ElementId, FontRegistry, LayoutEngine, MeasureText, TextConfig};
+
+/// Builds a font with 1000 units per em where 'a' to 'z' are `advance` units wide and everything else is 1000.
+fn test_font(advance: u16) -> Vec<u8> {
+    fn table(tag: &[u8; 4], data: Vec<u8>) -> ([u8; 4], Vec<u8>) {
+        (*tag, data)
+    }
+    let be16 = |value: u16| value.to_be_bytes().to_vec();
+    let be32 = |value: u32| value.to_be_bytes().to_vec();
+
+    let mut cmap = [be16(0), be16(1), be16(3), be16(10), be32(12)].concat();
+    cmap.extend([be16(12), be16(0), be32(28), be32(0), be32(1), be32('a' as u32), be32('z' as u32), be32(1)].concat());
+
+    let mut head = [be32(0x0001_0000), be32(0), be32(0), be32(0x5F0F_3CF5), be16(0), be16(1000)].concat();
+    head.resize(50, 0);
+    head.extend([be16(0), be16(0)].concat());
+
+    let mut hhea = [be32(0x0001_0000), be16(800), be16((-200i16) as u16), be16(0)].concat();
+    hhea.resize(34, 0);
+    hhea.extend(be16(2));
+
+    let hmtx = [be16(1000), be16(0), be16(advance), be16(0)].concat();
+    let maxp = [be32(0x0000_5000), be16(27)].concat();
+
+    let tables = [
+        table(b"cmap", cmap),
+        table(b"head", head),
+        table(b"hhea", hhea),
+        table(b"hmtx", hmtx),
+        table(b"maxp", maxp),
+    ];
+    let mut font = [be32(0x0001_0000), be16(tables.len() as u16), be16(0), be16(0), be16(0)].concat();
+    let mut offset = 12 + 16 * tables.len();
+    for (tag, data) in &tables {
+        font.extend([tag.to_vec(), be32(0), be32(offset as u32), be32(data.len() as u32)].concat());
+        offset += data.len().next_multiple_of(4);
+    }
+    for (_, data) in &tables {
+        font.extend(data);
+        font.resize(font.len().next_multiple_of(4), 0);
+    }
+    font
+}
+
+#[test]
+fn text_is_measured_with_the_font_metrics() {
+    let mut fonts = FontRegistry::new();
+    fonts.load(1, test_font(500)).unwrap();
+
+    // Without a line height the font's ascender and descender are used.
+    let config = TextConfig::new().font_id(1).font_size(20).line_height(0).end();
+    let size = fonts.measure_text("ab", config);
+    assert_eq!((size.x, size.y), (20.0, 20.0));
+
+    let spaced = TextConfig::new().font_id(1).font_size(20).letter_spacing(2).line_height(30).end();
+    let size = fonts.measure_text("ab A\nabc", spaced);
+    assert_eq!((size.x, size.y), (66.0, 60.0));
+}
+
+#[test]
+fn broken_fonts_are_rejected() {
+    let mut fonts = FontRegistry::new();
+    assert!(fonts.load(0, b"not a font".to_vec()).is_err());
+    assert!(!fonts.contains(0));
+}
+
+#[test]
+#[serial]
+fn layouts_use_the_font_metrics() {
+    let mut fonts = FontRegistry::new();
+    fonts.load(0, test_font(500)).unwrap();
+    let mut layout = LayoutEngine::<(), (), ()>::new((800.0, 600.0), fonts);
+
+    layout.begin_layout();
+    layout.element(&ElementConfiguration::new().id("label").end(), |layout| {
+        layout.text("hello", &TextConfig::new().font_size(10).line_height(0).end());
+    });
+    layout.end_layout();
+
+    let label = layout.bounding_box("label").unwrap();
+    assert_eq!((label.width, label.height), (25.0, 10.0));
+}
+
+#[test]
+#[serial]
+fn replaced_fonts_are_measured_again_once_invalidated() {
+    let mut fonts = FontRegistry::new();
+    fonts.load(0, test_font(500)).unwrap();
+    fonts.load(1, test_font(500)).unwrap();
+    let mut layout = LayoutEngine::<(), (), ()>::new((800.0, 600.0), fonts);
+    let label_widths = |layout: &mut LayoutEngine<(), (), ()>| {
+        layout.begin_layout();
+        layout.element(&ElementConfiguration::new().direction(true).end(), |layout| {
+            for font_id in [0, 1] {
+                layout.element(&ElementConfiguration::new().id(ElementId::indexed("label", font_id as u32)).end(), |layout| {
+                    layout.text("hello", &TextConfig::new().font_id(font_id).font_size(10).line_height(0).end());
+                });
+            }
+        });
+        layout.end_layout();
+        [0, 1].map(|index| layout.bounding_box(ElementId::indexed("label", index)).unwrap().width)
+    };
+    assert_eq!(label_widths(&mut layout), [25.0, 25.0]);
+
+    layout.text_renderer_mut::<FontRegistry>().unwrap().load(1, test_font(1000)).unwrap();
+    // The old measurements are kept until they are invalidated.
+    assert_eq!(label_widths(&mut layout), [25.0, 25.0]);
+    layout.invalidate_font_measurements(1);
+    assert_eq!(label_widths(&mut layout), [25.0, 50.0]);
+}
+
+#[test]
+fn debug_output_leaves_out_the_font_file() {
+    let mut fonts = FontRegistry::new();
+    fonts.load(1, test_font(500)).unwrap();
+
+    let debug = format!("{fonts:?}");
+    assert!(debug.contains("units_per_em: 1000"));
+    assert!(debug.len() < 200, "{debug}");
+}
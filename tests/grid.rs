@@ -0,0 +1,133 @@
+use serial_test::serial;
+use telera_layout::{Cell, ElementConfiguration, ElementId, Grid, LayoutEngine, Sizing, TextConfig, Track};
+
+mod common;
+use common::FixedMeasurer;
+
+fn position(layout: &LayoutEngine<(), (), ()>, id: ElementId) -> (f32, f32, f32, f32) {
+    let bounding_box = layout.bounding_box(id).unwrap();
+    (bounding_box.x, bounding_box.y, bounding_box.width, bounding_box.height)
+}
+
+#[test]
+#[serial]
+fn columns_line_up_across_rows() {
+    let mut layout = LayoutEngine::<(), (), ()>::new((800.0, 600.0), FixedMeasurer);
+    let settings = ElementConfiguration::new().id("settings").width(Sizing::Fixed(410.0)).height(Sizing::Fixed(400.0)).end();
+    let grid = Grid::new()
+        .columns(&[Track::Fit, Track::Grow(1.0), Track::Grow(2.0)])
+        .rows(&[Track::Fixed(20.0), Track::Fit, Track::Percent(0.5)])
+        .gaps(10, 5)
+        .end();
+    let field = |name: &'static str| ElementConfiguration::new().id(name).width(Sizing::grow()).height(Sizing::grow()).end();
+    let label = TextConfig::new().line_height(30).end();
+
+    layout.begin_layout();
+    layout.grid(&settings, &grid, |cells| {
+        cells.text(Cell::at(0, 0), "name", &label);
+        cells.element(Cell::at(0, 1), &field("name field"), |_| {});
+        cells.text(Cell::at(1, 0), "e-mail", &label);
+        cells.element(Cell::at(1, 1), &field("e-mail field"), |_| {});
+        cells.element(Cell::at(2, 0).span(1, 2), &field("notes"), |_| {});
+        cells.element(Cell::at(0, 2).span(3, 1), &field("avatar"), |_| {});
+    });
+    layout.end_layout();
+
+    // The first column fits "e-mail", the other two share the remaining 330 by 1 to 2.
+    assert_eq!(position(&layout, "name field".into()), (70.0, 0.0, 110.0, 20.0));
+    assert_eq!(position(&layout, "e-mail field".into()), (70.0, 25.0, 110.0, 30.0));
+    // The last row is half of the grid's height.
+    assert_eq!(position(&layout, "notes".into()), (0.0, 60.0, 180.0, 200.0));
+    assert_eq!(position(&layout, "avatar".into()), (190.0, 0.0, 220.0, 260.0));
+}
+
+#[test]
+#[serial]
+#[should_panic(expected = "can not overlap")]
+fn overlapping_cells_are_rejected() {
+    let mut layout = LayoutEngine::<(), (), ()>::new((800.0, 600.0), FixedMeasurer);
+    let grid = Grid::new().columns(&[Track::Fit, Track::Fit]).rows(&[Track::Fit]).end();
+
+    layout.begin_layout();
+    layout.grid(&ElementConfiguration::new(), &grid, |cells| {
+        cells.text(Cell::at(0, 0).span(1, 2), "wide", &TextConfig::new());
+        cells.text(Cell::at(0, 1), "clash", &TextConfig::new());
+    });
+}
+
+#[test]
+#[serial]
+fn growing_rows_fit_their_cells_when_the_grid_fits_its_content() {
+    let mut layout = LayoutEngine::<(), (), ()>::new((800.0, 600.0), FixedMeasurer);
+    let list = ElementConfiguration::new().id("list").width(Sizing::Fixed(200.0)).end();
+    let grid = Grid::new().columns(&[Track::Grow(1.0)]).rows(&[Track::Grow(1.0), Track::Grow(1.0)]).end();
+    let label = TextConfig::new().line_height(20).end();
+
+    for _ in 0..2 {
+        layout.begin_layout();
+        layout.grid(&list, &grid, |cells| {
+            cells.text(Cell::at(0, 0), "first", &label);
+            cells.text(Cell::at(1, 0), "second", &label);
+        });
+        layout.end_layout();
+        assert_eq!(layout.bounding_box("list").unwrap().height, 40.0);
+    }
+}
+
+#[test]
+#[serial]
+fn fitting_cells_settle_at_their_own_size() {
+    let mut layout = LayoutEngine::<(), (), ()>::new((800.0, 600.0), FixedMeasurer);
+    let table = ElementConfiguration::new().id("table").width(Sizing::Fixed(400.0)).end();
+    let grid = Grid::new().columns(&[Track::Fit, Track::Fit]).rows(&[Track::Fit]).gaps(10, 0).end();
+    let label = TextConfig::new().line_height(20).end();
+
+    // The first cell is arranged by its size in the previous frame, after which its content grows.
+    for content in ["two", "two", "two words", "two words"] {
+        layout.begin_layout();
+        layout.grid(&table, &grid, |cells| {
+            cells.element(Cell::at(0, 0), &ElementConfiguration::new().id("first").end(), move |layout| layout.text(content, &label));
+            // Cells without an id are told apart by their position.
+            cells.element(Cell::at(0, 1), &ElementConfiguration::new().end(), move |layout| layout.text("three more words", &label));
+        });
+        layout.end_layout();
+        eprintln!("{:?}", position(&layout, "first".into()));
+    }
+
+    assert_eq!(position(&layout, "first".into()), (0.0, 0.0, 90.0, 20.0));
+    assert_eq!(layout.bounding_box("table").unwrap().height, 20.0);
+}
+
+#[test]
+#[serial]
+fn growing_grids_shrink_with_their_parent() {
+    let mut layout = LayoutEngine::<(), (), ()>::new((800.0, 600.0), FixedMeasurer);
+    let sidebar = ElementConfiguration::new().width(Sizing::Fixed(100.0)).end();
+    let table = ElementConfiguration::new().id("table").width(Sizing::grow()).end();
+    let grid = Grid::new().columns(&[Track::Grow(1.0), Track::Grow(1.0)]).rows(&[Track::Fixed(20.0)]).end();
+    let cell = |name: &'static str| ElementConfiguration::new().id(name).width(Sizing::grow()).height(Sizing::grow()).end();
+
+    for _ in 0..2 {
+        layout.begin_layout();
+        layout.element(&sidebar, |layout| {
+            layout.grid(&table, &grid, |cells| {
+                cells.element(Cell::at(0, 0), &cell("left"), |_| {});
+                cells.element(Cell::at(0, 1), &cell("right"), |_| {});
+            });
+        });
+        layout.end_layout();
+    }
+
+    assert_eq!(position(&layout, "right".into()), (50.0, 0.0, 50.0, 20.0));
+}
+
+#[test]
+#[serial]
+#[should_panic(expected = "Invalid grid track: percentages must be between 0.0 and 1.0, got 1.5")]
+fn percent_tracks_are_validated() {
+    let mut layout = LayoutEngine::<(), (), ()>::new((800.0, 600.0), FixedMeasurer);
+    let grid = Grid::new().columns(&[Track::Percent(1.5)]).rows(&[Track::Fit]).end();
+
+    layout.begin_layout();
+    layout.grid(&ElementConfiguration::new().width(Sizing::Fixed(100.0)).end(), &grid, |_| {});
+}
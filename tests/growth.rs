@@ -0,0 +1,124 @@
+use serial_test::serial;
+use telera_layout::{ElementConfiguration, LayoutEngine, LayoutEngineBuilder, LayoutError, Sizing};
+
+mod common;
+use common::FixedMeasurer;
+
+/// Declares a 100px high scrolling list of 50px rows.
+fn frame(layout: &mut LayoutEngine<(), (), ()>, rows: usize) {
+    let list = ElementConfiguration::new()
+        .id("list")
+        .width(Sizing::Fixed(200.0))
+        .height(Sizing::Fixed(100.0))
+        .direction(true)
+        .scroll_container(true, false)
+        .end();
+    let row = ElementConfiguration::new().width(Sizing::grow()).height(Sizing::Fixed(50.0)).end();
+
+    layout.begin_layout();
+    layout.element(&list, |layout| {
+        layout.element(ElementConfiguration::new().id("first row").width(Sizing::grow()).height(Sizing::Fixed(50.0)), |_| {});
+        for _ in 1..rows {
+            layout.element(&row, |_| {});
+        }
+    });
+    layout.end_layout();
+}
+
+fn small_engine() -> LayoutEngine<(), (), ()> {
+    LayoutEngineBuilder::new()
+        .dimensions(800.0, 600.0)
+        .max_element_count(16)
+        .max_element_count_limit(50)
+        .build(FixedMeasurer)
+}
+
+#[test]
+#[serial]
+fn frames_that_run_out_of_elements_are_truncated() {
+    let mut layout = small_engine();
+    frame(&mut layout, 10);
+    assert!(!layout.truncated());
+
+    frame(&mut layout, 20);
+    assert!(layout.truncated());
+    assert!(layout.errors().contains(&LayoutError::ElementsCapacityExceeded));
+}
+
+#[test]
+#[serial]
+fn the_next_frame_doubles_the_capacity() {
+    let mut layout = small_engine();
+    frame(&mut layout, 20);
+    assert_eq!(layout.max_element_count(), 16);
+
+    frame(&mut layout, 20);
+    assert_eq!(layout.max_element_count(), 32);
+    assert!(!layout.truncated());
+    assert!(layout.errors().is_empty());
+}
+
+#[test]
+#[serial]
+fn growth_stops_at_the_limit() {
+    let mut layout = small_engine();
+    for _ in 0..4 {
+        frame(&mut layout, 100);
+    }
+    assert_eq!(layout.max_element_count(), 50);
+    assert!(layout.truncated());
+}
+
+#[test]
+#[serial]
+fn scroll_positions_survive_growth() {
+    let mut layout = small_engine();
+    frame(&mut layout, 10);
+    layout.pointer_state(10.0, 10.0, false);
+    layout.update_scroll_containers(false, 0.0, -5.0, 0.016);
+    frame(&mut layout, 10);
+    frame(&mut layout, 10);
+    assert_eq!(layout.bounding_box("first row").unwrap().y, -50.0);
+
+    frame(&mut layout, 20);
+    assert!(layout.truncated());
+    frame(&mut layout, 20);
+    assert!(!layout.truncated());
+    assert_eq!(layout.bounding_box("first row").unwrap().y, -50.0);
+    frame(&mut layout, 20);
+    assert_eq!(layout.bounding_box("first row").unwrap().y, -50.0);
+}
+
+#[test]
+#[serial]
+fn scrolling_continues_after_a_truncated_frame() {
+    let mut layout = LayoutEngineBuilder::new()
+        .dimensions(800.0, 600.0)
+        .max_element_count(16)
+        .auto_grow(false)
+        .build(FixedMeasurer);
+    let scroll = |layout: &mut LayoutEngine<(), (), ()>| {
+        layout.pointer_state(10.0, 10.0, false);
+        layout.update_scroll_containers(false, 0.0, -5.0, 0.016);
+    };
+    frame(&mut layout, 10);
+    scroll(&mut layout);
+    frame(&mut layout, 10);
+    assert_eq!(layout.bounding_box("first row").unwrap().y, -50.0);
+
+    // Scrolling is ignored while frames are truncated, and the position from before is kept.
+    frame(&mut layout, 20);
+    assert!(layout.truncated());
+    scroll(&mut layout);
+    frame(&mut layout, 20);
+    assert!(layout.truncated());
+    frame(&mut layout, 10);
+    assert_eq!(layout.bounding_box("first row").unwrap().y, -50.0);
+
+    scroll(&mut layout);
+    frame(&mut layout, 10);
+    assert_eq!(layout.bounding_box("first row").unwrap().y, -100.0);
+    scroll(&mut layout);
+    frame(&mut layout, 10);
+    assert_eq!(layout.bounding_box("first row").unwrap().y, -150.0);
+}
@@ -0,0 +1,81 @@
+use serial_test::serial;
+use telera_layout::{ElementConfiguration, LayoutEngine, RenderCommand, Sizing};
+
+mod common;
+use common::FixedMeasurer;
+
+#[test]
+#[serial]
+fn images_keep_their_aspect_ratio() {
+    let mut layout = LayoutEngine::<String, (), ()>::new((800.0, 600.0), FixedMeasurer);
+    layout.begin_layout();
+    let thumbnail = layout.store_image(String::from("thumbnail.png"));
+    let gallery = ElementConfiguration::new().width(Sizing::Fixed(400.0)).height(Sizing::Fixed(600.0)).direction(true).end();
+    let image = ElementConfiguration::new()
+        .width(Sizing::grow())
+        .image(thumbnail)
+        .image_dimensions(200.0, 100.0)
+        .radius_all(4.0)
+        .end();
+    let square = ElementConfiguration::new().width(Sizing::Fixed(50.0)).aspect_ratio(1.0).image(thumbnail).end();
+
+    layout.element(&gallery, |layout| {
+        layout.element(&image, |_| {});
+        layout.element(&square, |_| {});
+    });
+    let commands = layout.end_layout();
+
+    let images: Vec<_> = commands.iter().filter_map(|command| match command {
+        RenderCommand::Image(image) => Some(image),
+        _ => None,
+    }).collect();
+    assert_eq!(images.len(), 2);
+
+    assert_eq!((images[0].bounding_box.width, images[0].bounding_box.height), (400.0, 200.0));
+    assert_eq!((images[0].dimensions.x, images[0].dimensions.y), (200.0, 100.0));
+    assert_eq!(images[0].corner_radii.top_left, 4.0);
+    assert_eq!(images[0].data, "thumbnail.png");
+
+    assert_eq!((images[1].bounding_box.width, images[1].bounding_box.height), (50.0, 50.0));
+    assert_eq!((images[1].dimensions.x, images[1].dimensions.y), (50.0, 50.0));
+}
+
+#[test]
+#[serial]
+fn custom_elements_and_layout_settings_come_back_typed() {
+    let mut layout = LayoutEngine::<(), u32, &str>::new((800.0, 600.0), FixedMeasurer);
+
+    layout.begin_layout();
+    let chart = layout.store_custom_element(7);
+    let settings = layout.store_layout_settings("outlined");
+    let config = ElementConfiguration::new()
+        .width(Sizing::Fixed(50.0))
+        .height(Sizing::Fixed(50.0))
+        .custom_element(chart)
+        .custom_layout_settings(settings)
+        .end();
+    layout.element(&config, |_| {});
+    let commands = layout.end_layout();
+
+    match &commands[0] {
+        RenderCommand::Custom(custom) => {
+            assert_eq!(*custom.data, 7);
+            assert_eq!(custom.custom_layout_settings, Some(&"outlined"));
+        }
+        command => panic!("expected a custom element, got {command:?}"),
+    }
+}
+
+#[test]
+#[serial]
+#[should_panic(expected = "in the frame that created them")]
+fn handles_expire_with_their_frame() {
+    let mut layout = LayoutEngine::<String, (), ()>::new((800.0, 600.0), FixedMeasurer);
+
+    layout.begin_layout();
+    let thumbnail = layout.store_image(String::from("thumbnail.png"));
+    layout.end_layout();
+
+    layout.begin_layout();
+    layout.element(ElementConfiguration::new().image(thumbnail), |_| {});
+}
@@ -0,0 +1,74 @@
+use serial_test::serial;
+use telera_layout::{ElementConfiguration, ElementId, Interaction, LayoutEngine, Sizing};
+
+mod common;
+use common::FixedMeasurer;
+
+fn declare(layout: &mut LayoutEngine<(), (), ()>) {
+    let button = ElementConfiguration::new().id("button").width(Sizing::Fixed(100.0)).height(Sizing::Fixed(50.0)).end();
+    let other = ElementConfiguration::new().id("other").width(Sizing::Fixed(100.0)).height(Sizing::Fixed(50.0)).end();
+    layout.begin_layout();
+    layout.element(&button, |_| {});
+    layout.element(&other, |_| {});
+    layout.end_layout();
+}
+
+/// Moves the pointer and lays out the next frame, returning the events of the two buttons.
+fn frame(layout: &mut LayoutEngine<(), (), ()>, x: f32, y: f32, is_down: bool) -> Vec<(ElementId, Interaction)> {
+    layout.pointer_state(x, y, is_down);
+    let buttons = [ElementId::new("button"), ElementId::new("other")];
+    let events = layout.interaction_events()
+        .iter()
+        .filter(|event| buttons.contains(&event.id))
+        .map(|event| (event.id, event.interaction))
+        .collect();
+
+    declare(layout);
+    events
+}
+
+#[test]
+#[serial]
+fn button_interactions() {
+    let button = ElementId::new("button");
+    let other = ElementId::new("other");
+    let mut layout = LayoutEngine::<(), (), ()>::new((800.0, 600.0), FixedMeasurer);
+
+    frame(&mut layout, -1.0, -1.0, false);
+    assert_eq!(frame(&mut layout, 10.0, 10.0, false), vec![(button, Interaction::HoverEnter)]);
+    assert!(frame(&mut layout, 20.0, 10.0, false).is_empty());
+    assert_eq!(frame(&mut layout, 20.0, 10.0, true), vec![(button, Interaction::Press)]);
+    assert!(frame(&mut layout, 20.0, 10.0, true).is_empty());
+    assert_eq!(frame(&mut layout, 20.0, 10.0, false), vec![(button, Interaction::Release), (button, Interaction::Click)]);
+
+    frame(&mut layout, 20.0, 10.0, true);
+    assert_eq!(frame(&mut layout, 150.0, 10.0, false), vec![
+        (button, Interaction::HoverLeave),
+        (other, Interaction::HoverEnter),
+        (other, Interaction::Release),
+    ]);
+}
+
+#[test]
+#[serial]
+fn queries_cover_every_call_since_the_last_layout() {
+    let mut layout = LayoutEngine::<(), (), ()>::new((800.0, 600.0), FixedMeasurer);
+    frame(&mut layout, -1.0, -1.0, false);
+    frame(&mut layout, 10.0, 10.0, false);
+
+    layout.pointer_state(10.0, 10.0, true);
+    layout.pointer_state(10.0, 10.0, false);
+    assert!(layout.pressed("button"));
+    assert!(layout.released("button"));
+    assert!(layout.clicked("button"));
+    assert!(!layout.clicked("other"));
+
+    layout.begin_layout();
+    assert!(layout.clicked("button"));
+    layout.end_layout();
+    declare(&mut layout);
+
+    layout.pointer_state(10.0, 10.0, false);
+    assert!(!layout.clicked("button"));
+    assert!(layout.interaction_events().is_empty());
+}
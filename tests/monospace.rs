@@ -0,0 +1,33 @@
+use serial_test::serial;
+use telera_layout::{ElementConfiguration, LayoutEngine, MeasureText, MonospaceMeasurer, TextConfig};
+
+#[test]
+fn wide_graphemes_take_two_cells() {
+    assert_eq!(MonospaceMeasurer::cells("abc"), 3);
+    assert_eq!(MonospaceMeasurer::cells("日本語"), 6);
+    assert_eq!(MonospaceMeasurer::cells("e\u{301}"), 1);
+    assert_eq!(MonospaceMeasurer::cells("👍🏽"), 2);
+    assert_eq!(MonospaceMeasurer::cells("👨‍👩‍👧"), 2);
+}
+
+#[test]
+fn text_is_measured_in_cells() {
+    let mut measurer = MonospaceMeasurer::new(8.0, 16.0);
+    let size = measurer.measure_text("hello\n日本", TextConfig::new());
+    assert_eq!((size.x, size.y), (40.0, 32.0));
+}
+
+#[test]
+#[serial]
+fn layouts_come_out_in_whole_cells() {
+    let mut layout = LayoutEngine::<(), (), ()>::new((80.0, 24.0), MonospaceMeasurer::default());
+
+    layout.begin_layout();
+    layout.element(&ElementConfiguration::new().id("status").padding_all(1).end(), |layout| {
+        layout.text("ready 準備", &TextConfig::new().line_height(0).end());
+    });
+    layout.end_layout();
+
+    let status = layout.bounding_box("status").unwrap();
+    assert_eq!((status.width, status.height), (12.0, 3.0));
+}
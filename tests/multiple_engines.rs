@@ -0,0 +1,107 @@
+use serial_test::serial;
+use telera_layout::{ElementConfiguration, LayoutEngine, LayoutError, RenderCommand, Sizing, TextConfig};
+
+mod common;
+use common::FixedMeasurer;
+
+fn root_width(commands: &[RenderCommand<(), (), ()>]) -> Option<f32> {
+    commands.iter().find_map(|command| match command {
+        RenderCommand::Rectangle(rectangle) => Some(rectangle.bounding_box.width),
+        _ => None,
+    })
+}
+
+fn declare_panel(layout: &mut LayoutEngine<(), (), ()>, id: &'static str, text: &'static str) {
+    let config = ElementConfiguration::new()
+        .id(id)
+        .width(Sizing::grow())
+        .height(Sizing::grow())
+        .color([10, 20, 30, 255].into())
+        .end();
+    layout.element(&config, |layout| {
+        layout.text(text, &TextConfig::new());
+    });
+}
+
+#[test]
+#[serial]
+fn interleaved_layouts_stay_separate() {
+    let mut main_window = LayoutEngine::<(), (), ()>::new((800.0, 600.0), FixedMeasurer);
+    let mut panel = LayoutEngine::<(), (), ()>::new((200.0, 100.0), FixedMeasurer);
+
+    main_window.begin_layout();
+    panel.begin_layout();
+    declare_panel(&mut main_window, "main", "main window");
+    declare_panel(&mut panel, "panel", "panel");
+    let panel_commands = panel.end_layout();
+    let main_commands = main_window.end_layout();
+
+    assert_eq!(root_width(&main_commands), Some(800.0));
+    assert_eq!(root_width(&panel_commands), Some(200.0));
+
+    assert!(main_window.bounding_box(main_window.get_element_id("main")).is_some());
+    assert!(main_window.bounding_box(main_window.get_element_id("panel")).is_none());
+    assert!(panel.bounding_box(panel.get_element_id("panel")).is_some());
+    assert!(panel.bounding_box(panel.get_element_id("main")).is_none());
+}
+
+#[test]
+#[serial]
+fn pointer_state_only_affects_its_own_engine() {
+    let mut main_window = LayoutEngine::<(), (), ()>::new((800.0, 600.0), FixedMeasurer);
+    let mut panel = LayoutEngine::<(), (), ()>::new((200.0, 100.0), FixedMeasurer);
+
+    for _ in 0..2 {
+        main_window.begin_layout();
+        declare_panel(&mut main_window, "main", "main window");
+        main_window.end_layout();
+
+        panel.begin_layout();
+        declare_panel(&mut panel, "panel", "panel");
+        panel.end_layout();
+    }
+
+    main_window.pointer_state(10.0, 10.0, false);
+    panel.pointer_state(500.0, 500.0, false);
+
+    assert!(main_window.pointer_over(main_window.get_element_id("main")));
+    assert!(!panel.pointer_over(panel.get_element_id("panel")));
+}
+
+#[test]
+#[serial]
+fn errors_are_reported_to_the_engine_that_caused_them() {
+    let mut main_window = LayoutEngine::<(), (), ()>::new((800.0, 600.0), FixedMeasurer);
+    let mut panel = LayoutEngine::<(), (), ()>::new((200.0, 100.0), FixedMeasurer);
+
+    main_window.begin_layout();
+    panel.begin_layout();
+    declare_panel(&mut main_window, "duplicate", "first");
+    declare_panel(&mut panel, "duplicate", "other engine");
+    declare_panel(&mut main_window, "duplicate", "second");
+    panel.end_layout();
+    main_window.end_layout();
+
+    assert!(main_window.errors().contains(&LayoutError::DuplicateId));
+    assert!(panel.errors().is_empty());
+}
+
+#[test]
+#[serial]
+fn dropping_an_engine_leaves_the_others_usable() {
+    let mut main_window = LayoutEngine::<(), (), ()>::new((800.0, 600.0), FixedMeasurer);
+
+    {
+        let mut panel = LayoutEngine::<(), (), ()>::new((200.0, 100.0), FixedMeasurer);
+        panel.begin_layout();
+        declare_panel(&mut panel, "panel", "panel");
+        panel.end_layout();
+    }
+
+    main_window.begin_layout();
+    declare_panel(&mut main_window, "main", "main window");
+    let commands = main_window.end_layout();
+
+    assert_eq!(root_width(&commands), Some(800.0));
+    assert!(main_window.errors().is_empty());
+}
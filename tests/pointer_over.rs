@@ -0,0 +1,101 @@
+use serial_test::serial;
+use telera_layout::{ElementConfiguration, ElementId, Floating, LayoutEngine, PointerCapture, Sizing};
+
+mod common;
+use common::FixedMeasurer;
+
+fn declare(layout: &mut LayoutEngine<(), (), ()>, pass_through: bool) {
+    let base = ElementConfiguration::new().id("base").width(Sizing::Fixed(200.0)).height(Sizing::Fixed(200.0)).end();
+    let first = ElementConfiguration::new().id("first").width(Sizing::Fixed(100.0)).height(Sizing::Fixed(100.0)).end();
+    let second = ElementConfiguration::new().id("second").width(Sizing::Fixed(100.0)).height(Sizing::Fixed(100.0)).end();
+    let pointer_capture = if pass_through { PointerCapture::PassThrough } else { PointerCapture::Capture };
+    let overlay = ElementConfiguration::new()
+        .id("overlay")
+        .width(Sizing::Fixed(50.0))
+        .height(Sizing::Fixed(50.0))
+        .floating(Floating::new().z_index(1).pointer_capture(pointer_capture).end())
+        .end();
+    let badge = ElementConfiguration::new().id("badge").width(Sizing::Fixed(10.0)).height(Sizing::Fixed(10.0)).end();
+
+    layout.begin_layout();
+    layout.element(&base, |layout| {
+        layout.element(&first, |layout| {
+            layout.element(&overlay, |layout| {
+                layout.element(&badge, |_| {});
+            });
+        });
+        layout.element(&second, |_| {});
+    });
+    layout.end_layout();
+}
+
+#[test]
+#[serial]
+fn pointer_over_ids_are_ordered_from_the_top() {
+    let mut layout = LayoutEngine::<(), (), ()>::new((800.0, 600.0), FixedMeasurer);
+    declare(&mut layout, true);
+    declare(&mut layout, true);
+    layout.pointer_state(5.0, 5.0, false);
+
+    let ids = layout.pointer_over_ids();
+    let expected: Vec<ElementId> = ["badge", "overlay", "first", "base", "Clay__RootContainer"]
+        .into_iter()
+        .map(ElementId::new)
+        .collect();
+    assert_eq!(ids, expected);
+
+    layout.pointer_state(150.0, 5.0, false);
+    assert_eq!(layout.pointer_over_ids()[..2], [ElementId::new("second"), ElementId::new("base")]);
+}
+
+#[test]
+#[serial]
+fn capturing_floating_elements_hide_what_is_below() {
+    let mut layout = LayoutEngine::<(), (), ()>::new((800.0, 600.0), FixedMeasurer);
+    declare(&mut layout, false);
+    declare(&mut layout, false);
+    layout.pointer_state(20.0, 20.0, false);
+
+    assert_eq!(layout.pointer_over_ids(), vec![ElementId::new("overlay")]);
+}
+
+#[test]
+#[serial]
+fn children_sticking_out_of_floating_elements_keep_their_place() {
+    let base = ElementConfiguration::new().id("base").width(Sizing::Fixed(200.0)).height(Sizing::Fixed(200.0)).end();
+    let first = ElementConfiguration::new().id("first").width(Sizing::Fixed(100.0)).height(Sizing::Fixed(100.0)).end();
+    let top = ElementConfiguration::new()
+        .id("top")
+        .width(Sizing::Fixed(100.0))
+        .height(Sizing::Fixed(100.0))
+        .floating(Floating::new().z_index(2).pointer_capture(PointerCapture::PassThrough).end())
+        .end();
+    let lower = ElementConfiguration::new()
+        .id("lower")
+        .width(Sizing::Fixed(50.0))
+        .height(Sizing::Fixed(50.0))
+        .floating(Floating::new().z_index(1).offset(0.0, 60.0).pointer_capture(PointerCapture::PassThrough).end())
+        .end();
+    let wide = ElementConfiguration::new().id("wide").width(Sizing::Fixed(100.0)).height(Sizing::Fixed(10.0)).end();
+
+    let mut layout = LayoutEngine::<(), (), ()>::new((800.0, 600.0), FixedMeasurer);
+    for _ in 0..2 {
+        layout.begin_layout();
+        layout.element(&base, |layout| {
+            layout.element(&first, |layout| {
+                layout.element(&top, |_| {});
+                layout.element(&lower, |layout| {
+                    layout.element(&wide, |_| {});
+                });
+            });
+        });
+        layout.end_layout();
+    }
+    layout.pointer_state(75.0, 65.0, false);
+
+    let expected: Vec<ElementId> = ["top", "wide", "first", "base", "Clay__RootContainer"]
+        .into_iter()
+        .map(ElementId::new)
+        .collect();
+    assert_eq!(layout.pointer_over_ids(), expected);
+}
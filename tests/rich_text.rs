@@ -0,0 +1,99 @@
+use serial_test::serial;
+use telera_layout::{Color, ElementConfiguration, LayoutEngine, RenderCommand, Sizing, Span, TextConfig};
+
+mod common;
+use common::FixedMeasurer;
+
+#[test]
+#[serial]
+fn spans_wrap_as_one_paragraph() {
+    let mut layout = LayoutEngine::<(), (), ()>::new((800.0, 600.0), FixedMeasurer);
+    let plain = TextConfig::new().line_height(20).end();
+    let bold = TextConfig::new().font_id(1).font_size(20).line_height(20).color(Color { r: 255.0, g: 0.0, b: 0.0, a: 255.0 }).end();
+    let spans = [
+        Span::new("Hello ", &plain),
+        Span::new("bold", &bold),
+        Span::new("ed world", &plain),
+    ];
+
+    layout.begin_layout();
+    layout.add_rich_text(ElementConfiguration::new().id("paragraph").width(Sizing::Fixed(100.0)), &spans);
+    let commands = layout.end_layout();
+
+    let texts: Vec<_> = commands.iter().filter_map(|command| match command {
+        RenderCommand::Text(text) => Some(text),
+        _ => None,
+    }).collect();
+    let contents: Vec<_> = texts.iter().map(|text| text.text).collect();
+    assert_eq!(contents, ["Hello ", "bold", "ed ", "world"]);
+
+    let position = |index: usize| (texts[index].bounding_box.x, texts[index].bounding_box.y);
+    assert_eq!(position(0), (0.0, 0.0));
+    assert_eq!(position(1), (0.0, 20.0));
+    assert_eq!(position(2), (40.0, 20.0));
+    assert_eq!(position(3), (0.0, 40.0));
+
+    assert_eq!((texts[1].font_id, texts[1].font_size), (1, 20));
+    assert_eq!(texts[1].color.r, 255.0);
+    assert_eq!(texts[2].font_id, 0);
+
+    drop(commands);
+    assert_eq!(layout.bounding_box("paragraph").unwrap().height, 60.0);
+}
+
+fn texts(layout: &mut LayoutEngine<(), (), ()>, width: f32, spans: &[Span]) -> Vec<(String, f32, f32)> {
+    layout.begin_layout();
+    layout.add_rich_text(ElementConfiguration::new().id("paragraph").width(Sizing::Fixed(width)), spans);
+    layout.end_layout().iter().filter_map(|command| match command {
+        RenderCommand::Text(text) => Some((text.text.to_owned(), text.bounding_box.x, text.bounding_box.y)),
+        _ => None,
+    }).collect()
+}
+
+#[test]
+#[serial]
+fn new_lines_always_break_the_line() {
+    let mut layout = LayoutEngine::<(), (), ()>::new((800.0, 600.0), FixedMeasurer);
+    let plain = TextConfig::new().line_height(20).end();
+    let spans = [Span::new("one\ntwo", &plain), Span::new("\n\nthree", &plain)];
+
+    assert_eq!(texts(&mut layout, 800.0, &spans), [
+        ("one".to_owned(), 0.0, 0.0),
+        ("two".to_owned(), 0.0, 20.0),
+        ("three".to_owned(), 0.0, 60.0),
+    ]);
+    assert_eq!(layout.bounding_box("paragraph").unwrap().height, 80.0);
+}
+
+#[test]
+#[serial]
+fn whitespace_at_the_end_of_a_line_may_overflow_it() {
+    let mut layout = LayoutEngine::<(), (), ()>::new((800.0, 600.0), FixedMeasurer);
+    let plain = TextConfig::new().line_height(20).end();
+
+    assert_eq!(texts(&mut layout, 100.0, &[Span::new("abcd efghi next", &plain)]), [
+        ("abcd ".to_owned(), 0.0, 0.0),
+        ("efghi ".to_owned(), 50.0, 0.0),
+        ("next".to_owned(), 0.0, 20.0),
+    ]);
+}
+
+#[test]
+#[serial]
+fn growing_paragraphs_without_an_id_wrap_at_their_width() {
+    let mut layout = LayoutEngine::<(), (), ()>::new((800.0, 600.0), FixedMeasurer);
+    let sidebar = ElementConfiguration::new().id("sidebar").width(Sizing::Fixed(100.0)).end();
+    let spans = [Span::new("Hello world again", &TextConfig::new().line_height(20).end())];
+
+    for _ in 0..2 {
+        layout.begin_layout();
+        layout.element(&sidebar, |layout| {
+            layout.add_rich_text(ElementConfiguration::new().width(Sizing::grow()), &spans);
+        });
+        let commands = layout.end_layout();
+        // Only the paragraph clips, its lines fit.
+        let scissors = commands.iter().filter(|command| matches!(command, RenderCommand::ScissorStart(_))).count();
+        assert_eq!(scissors, 1);
+    }
+    assert_eq!(layout.bounding_box("sidebar").unwrap().height, 60.0);
+}
@@ -0,0 +1,66 @@
+use serial_test::serial;
+use telera_layout::{Color, ElementConfiguration, LayoutEngine, LayoutEngineBuilder, RenderCommand, Sizing, TextConfig};
+
+mod common;
+use common::FixedMeasurer;
+
+fn declare(layout: &mut LayoutEngine<(), (), ()>) {
+    let row = ElementConfiguration::new().id("row").width(Sizing::grow()).padding_left(10).end();
+    let first = ElementConfiguration::new()
+        .id("first")
+        .width(Sizing::Fixed(100.0))
+        .height(Sizing::Fixed(50.0))
+        .color(Color::default())
+        .radius_top_left(8.0)
+        .border_color(Color::default())
+        .border_left(4)
+        .end();
+    let second = ElementConfiguration::new().id("second").width(Sizing::Fixed(100.0)).height(Sizing::Fixed(50.0)).end();
+
+    layout.begin_layout();
+    layout.element(&row, |layout| {
+        layout.element(&first, |_| {});
+        layout.element(&second, |layout| {
+            layout.text("hi", &TextConfig::new().line_height(20).alignment_left().end());
+        });
+    });
+}
+
+#[test]
+#[serial]
+fn layouts_are_mirrored() {
+    let mut layout = LayoutEngineBuilder::new().dimensions(800.0, 600.0).right_to_left(true).build::<(), (), ()>(FixedMeasurer);
+    declare(&mut layout);
+    let commands = layout.end_layout();
+
+    for command in &commands {
+        match command {
+            RenderCommand::Rectangle(rectangle) => {
+                assert_eq!(rectangle.bounding_box.x, 690.0);
+                assert_eq!((rectangle.corner_radii.top_left, rectangle.corner_radii.top_right), (0.0, 8.0));
+            }
+            RenderCommand::Border(border) => {
+                assert_eq!(border.bounding_box.x, 690.0);
+                assert_eq!((border.width.left, border.width.right), (0, 4));
+            }
+            RenderCommand::Text(text) => assert_eq!(text.bounding_box.x, 670.0),
+            command => panic!("unexpected command {command:?}"),
+        }
+    }
+    drop(commands);
+
+    assert_eq!(layout.bounding_box("second").unwrap().x, 590.0);
+}
+
+#[test]
+#[serial]
+fn the_pointer_is_mirrored_too() {
+    let mut layout = LayoutEngine::<(), (), ()>::new((800.0, 600.0), FixedMeasurer);
+    layout.set_right_to_left(true);
+    declare(&mut layout);
+    layout.end_layout();
+
+    layout.pointer_state(750.0, 10.0, false);
+    assert!(layout.pointer_over("first"));
+    assert!(!layout.pointer_over("second"));
+}
@@ -0,0 +1,78 @@
+use serial_test::serial;
+use telera_layout::{ElementConfiguration, LayoutEngine, Sizing};
+
+mod common;
+use common::FixedMeasurer;
+
+/// Declares a 100px high list holding 500px of rows and returns the y of the first row.
+fn frame(layout: &mut LayoutEngine<(), (), ()>, list: &ElementConfiguration) -> f32 {
+    let row = ElementConfiguration::new().id("first row").width(Sizing::grow()).height(Sizing::Fixed(50.0)).end();
+    let rest = ElementConfiguration::new().width(Sizing::grow()).height(Sizing::Fixed(450.0)).end();
+
+    layout.begin_layout();
+    layout.element(list, |layout| {
+        layout.element(&row, |_| {});
+        layout.element(&rest, |_| {});
+    });
+    layout.end_layout();
+    layout.bounding_box("first row").unwrap().y
+}
+
+fn scrolled_row_position(list: &ElementConfiguration) -> f32 {
+    let mut layout = LayoutEngine::<(), (), ()>::new((800.0, 600.0), FixedMeasurer);
+    frame(&mut layout, list);
+    layout.pointer_state(10.0, 10.0, false);
+    layout.update_scroll_containers(false, 0.0, -5.0, 0.016);
+    frame(&mut layout, list);
+    frame(&mut layout, list)
+}
+
+fn list() -> ElementConfiguration {
+    ElementConfiguration::new().id("list").width(Sizing::Fixed(200.0)).height(Sizing::Fixed(100.0)).direction(true).end()
+}
+
+#[test]
+#[serial]
+fn scroll_containers_follow_their_scroll_position() {
+    assert_eq!(scrolled_row_position(list().scroll_container(true, false)), -50.0);
+}
+
+#[test]
+#[serial]
+fn clipped_elements_never_scroll() {
+    assert_eq!(scrolled_row_position(list().clip(true, false)), 0.0);
+}
+
+#[test]
+#[serial]
+fn clipped_children_leave_scrolling_to_their_list() {
+    let list = list().scroll_container(true, false).end();
+    let row = ElementConfiguration::new().id("first row").width(Sizing::grow()).height(Sizing::Fixed(50.0)).clip(true, true).end();
+    let rest = ElementConfiguration::new().width(Sizing::grow()).height(Sizing::Fixed(450.0)).end();
+    let mut layout = LayoutEngine::<(), (), ()>::new((800.0, 600.0), FixedMeasurer);
+    let frame = |layout: &mut LayoutEngine<(), (), ()>| {
+        layout.begin_layout();
+        layout.element(&list, |layout| {
+            layout.element(&row, |layout| {
+                layout.element(&ElementConfiguration::new().width(Sizing::Fixed(400.0)).height(Sizing::Fixed(400.0)).end(), |_| {});
+            });
+            layout.element(&rest, |_| {});
+        });
+        layout.end_layout();
+        layout.bounding_box("first row").unwrap().y
+    };
+
+    frame(&mut layout);
+    layout.pointer_state(10.0, 10.0, false);
+    layout.update_scroll_containers(true, 0.0, -5.0, 0.016);
+    frame(&mut layout);
+    assert_eq!(frame(&mut layout), -50.0);
+
+    layout.pointer_state(10.0, 20.0, true);
+    layout.update_scroll_containers(true, 0.0, 0.0, 0.016);
+    frame(&mut layout);
+    layout.pointer_state(10.0, 0.0, true);
+    layout.update_scroll_containers(true, 0.0, 0.0, 0.016);
+    frame(&mut layout);
+    assert_eq!(frame(&mut layout), -70.0);
+}
@@ -0,0 +1,54 @@
+use serial_test::serial;
+use telera_layout::{ElementConfiguration, LayoutEngine, Sizing, SizingError};
+
+mod common;
+use common::FixedMeasurer;
+
+#[test]
+fn valid_sizings_are_accepted() {
+    let config = ElementConfiguration::new()
+        .width(Sizing::Grow { min: 10.0, max: 100.0 })
+        .height(Sizing::Percent(1.0))
+        .try_end();
+    assert!(config.is_ok());
+}
+
+#[test]
+fn invalid_sizings_are_rejected() {
+    let rejected = |sizing: Sizing| ElementConfiguration::new().width(sizing).try_end().err();
+
+    assert_eq!(rejected(Sizing::Fixed(-1.0)), Some(SizingError::Negative(-1.0)));
+    assert_eq!(rejected(Sizing::Fit { min: 20.0, max: 10.0 }), Some(SizingError::MinGreaterThanMax { min: 20.0, max: 10.0 }));
+    assert_eq!(rejected(Sizing::Percent(1.5)), Some(SizingError::PercentOutOfRange(1.5)));
+    assert_eq!(rejected(Sizing::Grow { min: f32::NAN, max: 10.0 }), Some(SizingError::NotANumber));
+    assert_eq!(rejected(Sizing::Fixed(f32::INFINITY)), Some(SizingError::Infinite(f32::INFINITY)));
+    assert_eq!(rejected(Sizing::Fit { min: 0.0, max: f32::INFINITY }), Some(SizingError::Infinite(f32::INFINITY)));
+}
+
+#[test]
+#[should_panic(expected = "percentages must be between 0.0 and 1.0")]
+fn end_panics_on_invalid_sizing() {
+    ElementConfiguration::new().height(Sizing::Percent(-0.5)).end();
+}
+
+#[test]
+fn later_valid_sizings_replace_invalid_ones() {
+    let config = ElementConfiguration::new()
+        .width(Sizing::Percent(2.0))
+        .height(Sizing::Fixed(-1.0))
+        .width(Sizing::Percent(0.5))
+        .try_end();
+    assert_eq!(config.err(), Some(SizingError::Negative(-1.0)));
+
+    let config = ElementConfiguration::new().width(Sizing::Percent(2.0)).width(Sizing::Percent(0.5)).try_end();
+    assert!(config.is_ok());
+}
+
+#[test]
+#[serial]
+#[should_panic(expected = "Invalid element sizing")]
+fn engines_reject_invalid_sizing() {
+    let mut layout = LayoutEngine::<(), (), ()>::new((800.0, 600.0), FixedMeasurer);
+    layout.begin_layout();
+    layout.element(ElementConfiguration::new().width(Sizing::Percent(2.0)), |_| {});
+}
@@ -0,0 +1,60 @@
+use serial_test::serial;
+use telera_layout::{Color, ElementConfiguration, LayoutEngine, RenderCommand, Sizing, Style, TextConfig, TextStyle};
+
+mod common;
+use common::FixedMeasurer;
+
+#[test]
+fn unset_fields_never_overwrite_set_ones() {
+    let card = Style::new().padding_all(8).color([255, 255, 255, 255].into()).radius_all(4.0).end();
+    let highlighted = Style::new().color([255, 0, 0, 255].into()).padding_top(16).end();
+    let merged = card.merge(&highlighted);
+
+    assert_eq!(merged, Style::new()
+        .padding_all(8)
+        .padding_top(16)
+        .color([255, 0, 0, 255].into())
+        .radius_all(4.0)
+        .end());
+    assert_eq!(highlighted.merge(&Style::new()), highlighted);
+    assert_eq!(Style::new().merge(&highlighted), highlighted);
+}
+
+#[test]
+fn text_styles_apply_to_text_configs() {
+    let body = TextStyle::new().font_size(16).line_height(20).end();
+    let heading = body.merge(&TextStyle::new().font_size(24).end());
+
+    let config = TextConfig::new().font_id(2).apply(&heading).end();
+    assert_eq!((config.font_id, config.font_size, config.line_height), (2, 24, 20));
+}
+
+#[test]
+#[serial]
+fn styles_apply_to_element_configurations() {
+    let card = Style::new()
+        .width(Sizing::Fixed(100.0))
+        .height(Sizing::Fixed(50.0))
+        .color(Color::default())
+        .radius_all(4.0)
+        .end();
+    let config = ElementConfiguration::new()
+        .color([1, 2, 3, 4].into())
+        .apply(&card)
+        .apply(&Style::new().radius_top_left(8.0).end())
+        .end();
+
+    let mut layout = LayoutEngine::<(), (), ()>::new((800.0, 600.0), FixedMeasurer);
+    layout.begin_layout();
+    layout.element(&config, |_| {});
+    let commands = layout.end_layout();
+
+    match &commands[0] {
+        RenderCommand::Rectangle(rectangle) => {
+            assert_eq!((rectangle.bounding_box.width, rectangle.bounding_box.height), (100.0, 50.0));
+            assert_eq!(rectangle.color, Color::default());
+            assert_eq!((rectangle.corner_radii.top_left, rectangle.corner_radii.bottom_right), (8.0, 4.0));
+        }
+        command => panic!("expected a rectangle, got {command:?}"),
+    }
+}
@@ -0,0 +1,135 @@
+use std::cell::Cell;
+use std::rc::Rc;
+
+use serial_test::serial;
+use telera_layout::{ElementConfiguration, LayoutEngine, MeasureText, MonospaceMeasurer, Sizing, TextConfig, TextMeasurementStats, Vec2};
+
+struct CountingMeasurer {
+    character_width: f32,
+    calls: Rc<Cell<u32>>,
+}
+
+impl MeasureText for CountingMeasurer {
+    fn measure_text(&mut self, text: &str, text_config: TextConfig) -> Vec2 {
+        self.calls.set(self.calls.get() + 1);
+        Vec2 { x: text.len() as f32 * self.character_width, y: text_config.line_height as f32 }
+    }
+}
+
+fn label_width(layout: &mut LayoutEngine<(), (), ()>) -> f32 {
+    layout.begin_layout();
+    layout.element(&ElementConfiguration::new().id("label").end(), |layout| {
+        layout.text("hello", &TextConfig::new());
+    });
+    layout.end_layout();
+    layout.bounding_box("label").unwrap().width
+}
+
+#[test]
+#[serial]
+fn measurements_are_cached_across_frames() {
+    let calls = Rc::new(Cell::new(0));
+    let mut layout = LayoutEngine::<(), (), ()>::new((800.0, 600.0), CountingMeasurer { character_width: 10.0, calls: calls.clone() });
+
+    assert_eq!(label_width(&mut layout), 50.0);
+    let first_frame_calls = calls.get();
+    assert!(first_frame_calls > 0);
+
+    assert_eq!(label_width(&mut layout), 50.0);
+    assert_eq!(calls.get(), first_frame_calls);
+}
+
+#[test]
+#[serial]
+fn replacing_the_text_renderer_measures_again() {
+    let calls = Rc::new(Cell::new(0));
+    let mut layout = LayoutEngine::<(), (), ()>::new((800.0, 600.0), CountingMeasurer { character_width: 10.0, calls: calls.clone() });
+    assert_eq!(label_width(&mut layout), 50.0);
+
+    layout.set_text_renderer(CountingMeasurer { character_width: 20.0, calls });
+    assert_eq!(label_width(&mut layout), 100.0);
+}
+
+#[test]
+#[serial]
+fn the_text_renderer_is_handed_out_by_its_type() {
+    let mut layout = LayoutEngine::<(), (), ()>::new((800.0, 600.0), CountingMeasurer { character_width: 10.0, calls: Rc::default() });
+
+    assert!(layout.text_renderer_mut::<MonospaceMeasurer>().is_none());
+    layout.text_renderer_mut::<CountingMeasurer>().unwrap().character_width = 20.0;
+    layout.invalidate_text_measurements();
+    assert_eq!(label_width(&mut layout), 100.0);
+}
+
+fn two_fonts(layout: &mut LayoutEngine<(), (), ()>) -> TextMeasurementStats {
+    layout.begin_layout();
+    layout.element(&ElementConfiguration::new().direction(true).end(), |layout| {
+        layout.text("body text", &TextConfig::new().font_id(0).end());
+        layout.text("heading", &TextConfig::new().font_id(1).end());
+    });
+    layout.end_layout();
+    layout.text_measurement_stats()
+}
+
+#[test]
+#[serial]
+fn stats_count_cache_hits_and_measure_calls() {
+    let mut layout = LayoutEngine::<(), (), ()>::new((800.0, 600.0), CountingMeasurer { character_width: 10.0, calls: Rc::default() });
+
+    let first = two_fonts(&mut layout);
+    assert_eq!((first.cache_hits, first.cache_misses), (0, 2));
+    assert!(first.measure_calls > 0);
+
+    let second = two_fonts(&mut layout);
+    assert_eq!(second, TextMeasurementStats { measure_calls: 0, cache_hits: 2, cache_misses: 0 });
+}
+
+#[test]
+#[serial]
+fn invalidating_measures_text_again() {
+    let calls = Rc::new(Cell::new(0));
+    let mut layout = LayoutEngine::<(), (), ()>::new((800.0, 600.0), CountingMeasurer { character_width: 10.0, calls: calls.clone() });
+    two_fonts(&mut layout);
+    let all_calls = calls.get();
+
+    layout.invalidate_text_measurements();
+    let stats = two_fonts(&mut layout);
+    assert_eq!((stats.cache_hits, stats.cache_misses), (0, 2));
+    assert_eq!(stats.measure_calls, all_calls);
+}
+
+#[test]
+#[serial]
+fn invalidating_a_font_only_measures_its_text_again() {
+    let calls = Rc::new(Cell::new(0));
+    let mut layout = LayoutEngine::<(), (), ()>::new((800.0, 600.0), CountingMeasurer { character_width: 10.0, calls: calls.clone() });
+    two_fonts(&mut layout);
+    let all_calls = calls.get();
+
+    layout.invalidate_font_measurements(1);
+    let stats = two_fonts(&mut layout);
+    assert_eq!((stats.cache_hits, stats.cache_misses), (0, 2));
+    assert!(stats.measure_calls > 0 && stats.measure_calls < all_calls);
+    assert_eq!(calls.get(), all_calls + stats.measure_calls);
+}
+
+#[test]
+#[serial]
+fn wrap_items_are_measured_outside_the_stats() {
+    let measurer = || CountingMeasurer { character_width: 10.0, calls: Rc::default() };
+    let mut plain = LayoutEngine::<(), (), ()>::new((800.0, 600.0), measurer());
+    plain.begin_layout();
+    plain.element(&ElementConfiguration::new().end(), |layout| {
+        layout.text("two words", &TextConfig::new());
+    });
+    plain.end_layout();
+
+    let mut wrapped = LayoutEngine::<(), (), ()>::new((800.0, 600.0), measurer());
+    wrapped.begin_layout();
+    wrapped.wrap(&ElementConfiguration::new().width(Sizing::Fixed(200.0)).end(), |wrap| {
+        wrap.text("two words", &TextConfig::new());
+    });
+    wrapped.end_layout();
+
+    assert_eq!(wrapped.text_measurement_stats(), plain.text_measurement_stats());
+}
@@ -0,0 +1,40 @@
+use serial_test::serial;
+use telera_layout::{ElementConfiguration, LayoutEngine, RenderCommand, Sizing, TextConfig};
+
+mod common;
+use common::FixedMeasurer;
+
+fn texts(commands: &[RenderCommand<(), (), ()>]) -> Vec<String> {
+    commands.iter().filter_map(|command| match command {
+        RenderCommand::Text(text) => Some(text.text.to_string()),
+        _ => None,
+    }).collect()
+}
+
+#[test]
+#[serial]
+fn formatted_text_outlives_the_caller() {
+    let mut layout = LayoutEngine::<(), (), ()>::new((800.0, 2000.0), FixedMeasurer);
+    let config = ElementConfiguration::new().width(Sizing::grow()).height(Sizing::grow()).direction(true).end();
+
+    for frame in 0..3 {
+        layout.begin_layout();
+        layout.element(&config, |layout| {
+            layout.text("static", &TextConfig::new());
+            for counter in 0..100 {
+                let label = format!("frame {frame} counter {counter}");
+                layout.text(label.as_str(), &TextConfig::new());
+            }
+            layout.text(format!("owned {frame}"), &TextConfig::new());
+        });
+        let commands = layout.end_layout();
+
+        let texts = texts(&commands);
+        assert_eq!(texts.len(), 102);
+        assert_eq!(texts[0], "static");
+        for (counter, text) in texts[1..101].iter().enumerate() {
+            assert_eq!(text, &format!("frame {frame} counter {counter}"));
+        }
+        assert_eq!(texts[101], format!("owned {frame}"));
+    }
+}
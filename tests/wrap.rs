@@ -0,0 +1,122 @@
+use serial_test::serial;
+use telera_layout::{ElementConfiguration, ElementId, LayoutEngine, Sizing, TextConfig};
+
+mod common;
+use common::FixedMeasurer;
+
+fn chip(index: u32) -> ElementConfiguration {
+    ElementConfiguration::new().id(ElementId::indexed("chip", index)).width(Sizing::Fixed(40.0)).height(Sizing::Fixed(20.0)).end()
+}
+
+#[test]
+#[serial]
+fn items_wrap_onto_new_rows() {
+    let mut layout = LayoutEngine::<(), (), ()>::new((800.0, 600.0), FixedMeasurer);
+    let tags = ElementConfiguration::new().id("tags").width(Sizing::Fixed(100.0)).child_gap(10).align_children_x_center().end();
+
+    layout.begin_layout();
+    layout.wrap(&tags, |wrap| {
+        for index in 0..3 {
+            wrap.element(&chip(index), |_| {});
+        }
+        wrap.text("overflowing", &TextConfig::new().line_height(20).end());
+    });
+    layout.end_layout();
+
+    let position = |index| {
+        let bounding_box = layout.bounding_box(ElementId::indexed("chip", index)).unwrap();
+        (bounding_box.x, bounding_box.y)
+    };
+    assert_eq!(position(0), (5.0, 0.0));
+    assert_eq!(position(1), (55.0, 0.0));
+    assert_eq!(position(2), (30.0, 30.0));
+
+    let tags = layout.bounding_box("tags").unwrap();
+    assert_eq!((tags.width, tags.height), (100.0, 80.0));
+}
+
+#[test]
+#[serial]
+fn columns_wrap_along_the_height() {
+    let mut layout = LayoutEngine::<(), (), ()>::new((800.0, 600.0), FixedMeasurer);
+    let column = ElementConfiguration::new().id("column").height(Sizing::Fixed(50.0)).direction(true).child_gap(5).end();
+
+    layout.begin_layout();
+    layout.wrap(&column, |wrap| {
+        for index in 0..3 {
+            wrap.element(&chip(index), |_| {});
+        }
+    });
+    layout.end_layout();
+
+    assert_eq!(layout.bounding_box(ElementId::indexed("chip", 1)).unwrap().y, 25.0);
+    let third = layout.bounding_box(ElementId::indexed("chip", 2)).unwrap();
+    assert_eq!((third.x, third.y), (45.0, 0.0));
+}
+
+#[test]
+#[serial]
+fn growing_containers_wrap_at_their_own_width() {
+    let mut layout = LayoutEngine::<(), (), ()>::new((800.0, 600.0), FixedMeasurer);
+    let sidebar = ElementConfiguration::new().width(Sizing::Fixed(100.0)).end();
+    let tags = ElementConfiguration::new().id("tags").width(Sizing::grow()).child_gap(10).end();
+
+    for _ in 0..2 {
+        layout.begin_layout();
+        layout.element(&sidebar, |layout| {
+            layout.wrap(&tags, |wrap| {
+                for index in 0..3 {
+                    wrap.element(&chip(index), |_| {});
+                }
+            });
+        });
+        layout.end_layout();
+    }
+
+    let third = layout.bounding_box(ElementId::indexed("chip", 2)).unwrap();
+    assert_eq!((third.x, third.y), (0.0, 30.0));
+    assert_eq!(layout.bounding_box("tags").unwrap().width, 100.0);
+}
+
+/// Wraps three chips fitting their text in a 200 wide sidebar for a few frames.
+fn wrap_fitting_chips(layout: &mut LayoutEngine<(), (), ()>, tags: &ElementConfiguration, chip: impl Fn(u32) -> ElementConfiguration) {
+    let sidebar = ElementConfiguration::new().id("sidebar").width(Sizing::Fixed(200.0)).end();
+    for _ in 0..3 {
+        layout.begin_layout();
+        layout.element(&sidebar, |layout| {
+            layout.wrap(tags, |wrap| {
+                for index in 0..3 {
+                    wrap.element(&chip(index), |layout| layout.text("two words", &TextConfig::new().line_height(20).end()));
+                }
+            });
+        });
+        layout.end_layout();
+    }
+}
+
+#[test]
+#[serial]
+fn fitting_items_keep_their_text_on_one_line() {
+    let mut layout = LayoutEngine::<(), (), ()>::new((800.0, 600.0), FixedMeasurer);
+    let tags = ElementConfiguration::new().id("tags").width(Sizing::Fixed(200.0)).child_gap(10).end();
+    wrap_fitting_chips(&mut layout, &tags, |index| ElementConfiguration::new().id(ElementId::indexed("chip", index)).end());
+
+    let position = |index| {
+        let bounding_box = layout.bounding_box(ElementId::indexed("chip", index)).unwrap();
+        (bounding_box.x, bounding_box.y, bounding_box.width, bounding_box.height)
+    };
+    assert_eq!(position(0), (0.0, 0.0, 90.0, 20.0));
+    assert_eq!(position(1), (100.0, 0.0, 90.0, 20.0));
+    assert_eq!(position(2), (0.0, 30.0, 90.0, 20.0));
+}
+
+#[test]
+#[serial]
+fn containers_and_items_without_ids_are_arranged_too() {
+    let mut layout = LayoutEngine::<(), (), ()>::new((800.0, 600.0), FixedMeasurer);
+    let tags = ElementConfiguration::new().width(Sizing::grow()).child_gap(10).end();
+    wrap_fitting_chips(&mut layout, &tags, |_| ElementConfiguration::new());
+
+    // Two lines of chips 20 high, so the chips kept their text on one line.
+    assert_eq!(layout.bounding_box("sidebar").unwrap().height, 50.0);
+}